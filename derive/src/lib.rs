@@ -87,6 +87,88 @@ use crate::traits::{
 ///
 /// let _: u32 = bytemuck::cast(Generic { a: 4u32, b: PhantomData::<NotPod> });
 /// ```
+///
+/// # Size constants
+///
+/// With `#[bytemuck(pod_consts)]`, the derive also adds `POD_SIZE` and
+/// `POD_ALIGN` associated constants to the type, holding its size and
+/// alignment. They're opt-in so that the derive doesn't add inherent items to a
+/// type without being asked.
+///
+/// ```rust
+/// # use bytemuck::{Pod, Zeroable};
+/// #[derive(Copy, Clone, Pod, Zeroable)]
+/// #[repr(C)]
+/// #[bytemuck(pod_consts)]
+/// struct Header {
+///   magic: u32,
+///   len: u16,
+///   flags: u16,
+/// }
+///
+/// let buf = [0_u8; Header::POD_SIZE];
+/// assert_eq!(buf.len(), 8);
+/// assert_eq!(Header::POD_ALIGN, 4);
+/// ```
+///
+/// # Building from field bytes
+///
+/// With `#[bytemuck(field_bytes)]`, the derive also adds a
+/// `try_from_field_bytes(fields: &[&[u8]]) -> Result<Self, PodCastError>`
+/// constructor. It takes one byte slice per field, in declaration order, and
+/// reads each field from its slice. It fails with `PodCastError::SizeMismatch`
+/// if the number of slices or the length of any slice is wrong.
+///
+/// ```rust
+/// # use bytemuck::{Pod, PodCastError, Zeroable};
+/// #[derive(Copy, Clone, Pod, Zeroable)]
+/// #[repr(C)]
+/// #[bytemuck(field_bytes)]
+/// struct Header {
+///   magic: u32,
+///   len: u16,
+///   flags: u16,
+/// }
+///
+/// let header = Header::try_from_field_bytes(&[
+///   &7_u32.to_ne_bytes(),
+///   &2_u16.to_ne_bytes(),
+///   &0_u16.to_ne_bytes(),
+/// ])
+/// .unwrap();
+/// assert_eq!((header.magic, header.len, header.flags), (7, 2, 0));
+///
+/// let err = Header::try_from_field_bytes(&[&[0; 4], &[0; 2]]);
+/// assert_eq!(err.err(), Some(PodCastError::SizeMismatch));
+/// ```
+///
+/// # Splitting into columns
+///
+/// With `#[bytemuck(columns)]`, the derive also adds a
+/// `split_columns(items: &[Self])` function that copies each field of every item
+/// into its own `Vec`, giving a tuple with one column per field in declaration
+/// order. This turns an "array of structs" into a "struct of arrays", such as
+/// separate per-attribute buffers for a GPU. This needs the
+/// `extern_crate_alloc` feature of `bytemuck`.
+///
+/// ```rust
+/// # use bytemuck::{Pod, Zeroable};
+/// #[derive(Copy, Clone, Pod, Zeroable)]
+/// #[repr(C)]
+/// #[bytemuck(columns)]
+/// struct Vertex {
+///   pos: [f32; 3],
+///   uv: [f32; 2],
+/// }
+///
+/// let vertices = [
+///   Vertex { pos: [0.0, 1.0, 2.0], uv: [0.0, 0.5] },
+///   Vertex { pos: [3.0, 4.0, 5.0], uv: [1.0, 0.5] },
+/// ];
+/// let (positions, uvs) = Vertex::split_columns(&vertices);
+/// assert_eq!(positions, [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+/// assert_eq!(uvs, [[0.0, 0.5], [1.0, 0.5]]);
+/// ```
 #[proc_macro_derive(Pod, attributes(bytemuck))]
 pub fn derive_pod(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
   let expanded =
@@ -164,6 +246,28 @@ pub fn derive_anybitpattern(
 /// }
 /// ```
 ///
+/// The zero bit pattern must select a variant, and that variant's fields must
+/// all be `Zeroable`, otherwise the derive is rejected:
+///
+/// ```rust,compile_fail
+/// # use bytemuck_derive::{Zeroable};
+/// #[derive(Clone, Zeroable)]
+/// #[repr(u8)]
+/// enum NoZeroVariant {
+///   A(u32) = 1,
+///   B = 2,
+/// }
+/// ```
+/// ```rust,compile_fail
+/// # use bytemuck_derive::{Zeroable};
+/// #[derive(Clone, Zeroable)]
+/// #[repr(u8)]
+/// enum ZeroVariantNotZeroable {
+///   A(std::num::NonZeroU32) = 0,
+///   B(u32) = 1,
+/// }
+/// ```
+///
 /// # Custom bounds
 ///
 /// Custom bounds for the derived `Zeroable` impl can be given using the
@@ -289,6 +393,11 @@ pub fn derive_no_uninit(
 /// - The enum must be explicit `#[repr(Int)]`
 /// - All fields in variants must implement `CheckedBitPattern`
 /// - The enum must contain no generic parameters
+///
+/// For a struct, the generated `is_valid_bit_pattern` checks each field with
+/// that field's own `is_valid_bit_pattern`, in declaration order, and stops at
+/// the first field that fails. Fields which are themselves structs with a
+/// derived `CheckedBitPattern` are checked recursively in the same way.
 #[proc_macro_derive(CheckedBitPattern)]
 pub fn derive_maybe_pod(
   input: proc_macro::TokenStream,
@@ -61,6 +61,44 @@ impl Derivable for Pod {
     Ok(syn::parse_quote!(#crate_name::Pod))
   }
 
+  fn trait_impl(
+    input: &DeriveInput, crate_name: &TokenStream,
+  ) -> Result<(TokenStream, TokenStream)> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) =
+      input.generics.split_for_impl();
+    let consts = if has_bytemuck_flag(&input.attrs, "pod_consts") {
+      quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+          /// The size of this type in bytes.
+          pub const POD_SIZE: usize = ::core::mem::size_of::<Self>();
+          /// The alignment of this type in bytes.
+          pub const POD_ALIGN: usize = ::core::mem::align_of::<Self>();
+        }
+      }
+    } else {
+      quote!()
+    };
+    let field_bytes = if has_bytemuck_flag(&input.attrs, "field_bytes") {
+      generate_try_from_field_bytes(input, crate_name)?
+    } else {
+      quote!()
+    };
+    let columns = if has_bytemuck_flag(&input.attrs, "columns") {
+      generate_split_columns(input, crate_name)?
+    } else {
+      quote!()
+    };
+    Ok((
+      quote! {
+        #consts
+        #field_bytes
+        #columns
+      },
+      quote!(),
+    ))
+  }
+
   fn asserts(
     input: &DeriveInput, crate_name: &TokenStream,
   ) -> Result<TokenStream> {
@@ -1005,6 +1043,132 @@ fn generate_assert_no_padding(input: &DeriveInput) -> Result<TokenStream> {
   };})
 }
 
+/// Generates the `try_from_field_bytes` constructor for
+/// `#[bytemuck(field_bytes)]`, which reads each field out of its own byte
+/// slice.
+fn generate_try_from_field_bytes(
+  input: &DeriveInput, crate_name: &TokenStream,
+) -> Result<TokenStream> {
+  let name = &input.ident;
+  let (impl_generics, ty_generics, where_clause) =
+    input.generics.split_for_impl();
+  let fields = get_struct_fields(input)?;
+  let field_count = fields.len();
+  let field_types = get_field_types(fields).collect::<Vec<_>>();
+  let field_names =
+    fields.iter().enumerate().map(|(i, field)| match &field.ident {
+      Some(ident) => quote!(#ident),
+      None => {
+        let index = syn::Index::from(i);
+        quote!(#index)
+      }
+    });
+  let indices = 0..field_count;
+
+  // Generic fields need to be known as `AnyBitPattern` to be read.
+  let mut where_clause = where_clause.cloned();
+  if !input.generics.params.is_empty() {
+    let where_clause =
+      where_clause.get_or_insert_with(|| syn::parse_quote!(where));
+    for ty in &field_types {
+      where_clause
+        .predicates
+        .push(syn::parse_quote!(#ty: #crate_name::AnyBitPattern));
+    }
+    where_clause
+      .predicates
+      .push(syn::parse_quote!(#name #ty_generics: #crate_name::Zeroable));
+  }
+
+  Ok(quote! {
+    impl #impl_generics #name #ty_generics #where_clause {
+      /// Builds a value from the bytes of each field, given in declaration
+      /// order.
+      ///
+      /// Each slice is read unaligned, and must be exactly the size of its
+      /// field.
+      ///
+      /// ## Failure
+      ///
+      /// * If the number of slices isn't the number of fields, or any slice
+      ///   isn't the size of its field, this is `PodCastError::SizeMismatch`.
+      pub fn try_from_field_bytes(
+        fields: &[&[u8]],
+      ) -> ::core::result::Result<Self, #crate_name::PodCastError> {
+        if fields.len() != #field_count {
+          return ::core::result::Result::Err(
+            #crate_name::PodCastError::SizeMismatch,
+          );
+        }
+        let mut out: Self = #crate_name::Zeroable::zeroed();
+        #(
+          out.#field_names =
+            #crate_name::try_pod_read_unaligned::<#field_types>(fields[#indices])?;
+        )*
+        ::core::result::Result::Ok(out)
+      }
+    }
+  })
+}
+
+/// Generates the `split_columns` function for `#[bytemuck(columns)]`, which
+/// copies each field of a slice of the type out into its own `Vec`.
+fn generate_split_columns(
+  input: &DeriveInput, crate_name: &TokenStream,
+) -> Result<TokenStream> {
+  let name = &input.ident;
+  let (impl_generics, ty_generics, where_clause) =
+    input.generics.split_for_impl();
+  let fields = get_struct_fields(input)?;
+  let field_types = get_field_types(fields).collect::<Vec<_>>();
+  let field_names = fields
+    .iter()
+    .enumerate()
+    .map(|(i, field)| match &field.ident {
+      Some(ident) => quote!(#ident),
+      None => {
+        let index = syn::Index::from(i);
+        quote!(#index)
+      }
+    })
+    .collect::<Vec<_>>();
+  let columns = (0..fields.len())
+    .map(|i| Ident::new(&format!("column_{}", i), Span::call_site()))
+    .collect::<Vec<_>>();
+
+  // Generic fields need to be known as `Copy` to be copied out.
+  let mut where_clause = where_clause.cloned();
+  if !input.generics.params.is_empty() {
+    let where_clause =
+      where_clause.get_or_insert_with(|| syn::parse_quote!(where));
+    for ty in &field_types {
+      where_clause
+        .predicates
+        .push(syn::parse_quote!(#ty: ::core::marker::Copy));
+    }
+  }
+
+  let vec = quote!(#crate_name::__Vec);
+  Ok(quote! {
+    impl #impl_generics #name #ty_generics #where_clause {
+      /// Copies each field of `items` into its own `Vec`, giving one column
+      /// per field in declaration order.
+      #[allow(unused_variables, clippy::unused_unit)]
+      pub fn split_columns(items: &[Self]) -> (#(#vec<#field_types>,)*) {
+        #(
+          let mut #columns = #vec::with_capacity(items.len());
+        )*
+        for item in items {
+          #(
+            #columns.push(item.#field_names);
+          )*
+        }
+        (#(#columns,)*)
+      }
+    }
+  })
+}
+
 /// Check that all fields implement a given trait
 fn generate_fields_are_trait(
   input: &DeriveInput, enum_variant: Option<&Variant>, trait_: syn::Path,
@@ -1456,5 +1620,27 @@ pub fn bytemuck_crate_name(input: &DeriveInput) -> TokenStream {
   return crate_name;
 }
 
+/// Checks for a `#[bytemuck(flag)]` attribute on the type, such as
+/// `#[bytemuck(pod_consts)]`.
+fn has_bytemuck_flag(attributes: &[Attribute], flag: &str) -> bool {
+  let mut found = false;
+  for attr in attributes {
+    if !attr.path().is_ident("bytemuck") {
+      continue;
+    }
+
+    let _ = attr.parse_nested_meta(|meta| {
+      if meta.path.is_ident(flag) {
+        found = true;
+      } else if meta.input.peek(syn::Token![=]) {
+        // Skip over the value of other attributes, such as `crate = "..."`.
+        let _: syn::Expr = meta.value()?.parse()?;
+      }
+      Ok(())
+    });
+  }
+  found
+}
+
 const GENERATED_TYPE_DOCUMENTATION: &str =
   " `bytemuck`-generated type for internal purposes only.";
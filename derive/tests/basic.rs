@@ -342,6 +342,102 @@ fn passes_cast_struct() {
   );
 }
 
+#[derive(Debug, Copy, Clone, CheckedBitPattern, PartialEq, Eq)]
+#[repr(C)]
+struct CheckedBitPatternNestedStruct {
+  inner: CheckedBitPatternStruct,
+  flag: bool,
+  tail: CheckedBitPatternStruct,
+}
+
+#[test]
+fn checkedbitpattern_nested_struct() {
+  let pod = [0u8, 8, 1, 7, 56];
+  let res =
+    bytemuck::checked::from_bytes::<CheckedBitPatternNestedStruct>(&pod);
+  assert_eq!(
+    *res,
+    CheckedBitPatternNestedStruct {
+      inner: CheckedBitPatternStruct {
+        a: 0,
+        b: CheckedBitPatternEnumNonContiguous::B
+      },
+      flag: true,
+      tail: CheckedBitPatternStruct {
+        a: 7,
+        b: CheckedBitPatternEnumNonContiguous::E
+      },
+    }
+  );
+
+  // invalid enum inside the first nested struct
+  let pod = [0u8, 24, 1, 7, 56];
+  assert_eq!(
+    bytemuck::checked::try_from_bytes::<CheckedBitPatternNestedStruct>(&pod),
+    Err(CheckedCastError::InvalidBitPattern)
+  );
+  // invalid bool directly in the outer struct
+  let pod = [0u8, 8, 2, 7, 56];
+  assert_eq!(
+    bytemuck::checked::try_from_bytes::<CheckedBitPatternNestedStruct>(&pod),
+    Err(CheckedCastError::InvalidBitPattern)
+  );
+  // invalid enum inside the last nested struct
+  let pod = [0u8, 8, 1, 7, 57];
+  assert_eq!(
+    bytemuck::checked::try_from_bytes::<CheckedBitPatternNestedStruct>(&pod),
+    Err(CheckedCastError::InvalidBitPattern)
+  );
+}
+
+#[test]
+fn checkedbitpattern_struct_short_circuits() {
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  static CHECKS: AtomicUsize = AtomicUsize::new(0);
+
+  #[derive(Clone, Copy)]
+  #[repr(transparent)]
+  struct Counted(u8);
+  unsafe impl CheckedBitPattern for Counted {
+    type Bits = u8;
+    fn is_valid_bit_pattern(bits: &u8) -> bool {
+      CHECKS.fetch_add(1, Ordering::SeqCst);
+      *bits != 0
+    }
+  }
+
+  #[derive(Clone, Copy, CheckedBitPattern)]
+  #[repr(C)]
+  struct ThreeCounted {
+    a: Counted,
+    b: Counted,
+    c: Counted,
+  }
+
+  let res = bytemuck::checked::try_from_bytes::<ThreeCounted>(&[1u8, 0, 1]);
+  assert!(res.is_err());
+  assert_eq!(CHECKS.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn zeroable_enum_with_fieldful_zero_variant() {
+  #[derive(Debug, PartialEq, Zeroable)]
+  #[repr(u8)]
+  enum Foo {
+    A(u32) = 0,
+    B(String) = 1,
+  }
+  assert_eq!(Foo::zeroed(), Foo::A(0));
+
+  #[derive(Debug, PartialEq, Zeroable)]
+  #[repr(C)]
+  enum Bar {
+    A(u32, [u16; 3]),
+    B(Box<u32>),
+  }
+  assert_eq!(Bar::zeroed(), Bar::A(0, [0; 3]));
+}
+
 #[test]
 fn anybitpattern_implies_zeroable() {
   let test = AnyBitPatternTest::<isize, usize>::zeroed();
@@ -522,3 +618,126 @@ use bytemuck as reexport_name;
 #[bytemuck(crate = "reexport_name")]
 #[repr(C)]
 struct Issue93 {}
+
+#[derive(Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+#[bytemuck(pod_consts)]
+struct PodConsts {
+  a: u64,
+  b: [u16; 4],
+}
+
+#[derive(Copy, Clone, Pod, Zeroable)]
+#[repr(transparent)]
+#[bytemuck(crate = "reexport_name", pod_consts)]
+struct PodConstsGeneric<T: Pod> {
+  a: T,
+}
+
+#[test]
+fn pod_consts() {
+  let buf = [0u8; PodConsts::POD_SIZE];
+  let value: PodConsts = bytemuck::pod_read_unaligned(&buf);
+  assert_eq!(value.a, 0);
+  assert_eq!(PodConsts::POD_SIZE, 16);
+  assert_eq!(PodConsts::POD_ALIGN, 8);
+
+  assert_eq!(PodConstsGeneric::<u16>::POD_SIZE, 2);
+  assert_eq!(PodConstsGeneric::<[u32; 3]>::POD_SIZE, 12);
+  assert_eq!(PodConstsGeneric::<[u32; 3]>::POD_ALIGN, 4);
+}
+
+#[derive(Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+#[bytemuck(field_bytes)]
+struct FieldBytes {
+  id: u32,
+  kind: [u8; 2],
+  count: u16,
+}
+
+#[derive(Copy, Clone, Pod, Zeroable)]
+#[repr(C, packed)]
+#[bytemuck(field_bytes)]
+struct FieldBytesGeneric<T: Pod>(u8, T);
+
+#[test]
+fn field_bytes() {
+  let value = FieldBytes::try_from_field_bytes(&[
+    &0x0102_0304_u32.to_ne_bytes(),
+    b"AB",
+    &9_u16.to_ne_bytes(),
+  ])
+  .unwrap();
+  assert_eq!(value.id, 0x0102_0304);
+  assert_eq!(&value.kind, b"AB");
+  assert_eq!(value.count, 9);
+
+  // field slices can be at any alignment.
+  let buf = [0_u8, 1, 0, 0, 0];
+  let value =
+    FieldBytes::try_from_field_bytes(&[&buf[1..], &buf[..2], &buf[3..]])
+      .unwrap();
+  assert_eq!(value.id, u32::from_ne_bytes([1, 0, 0, 0]));
+
+  let wrong_count = FieldBytes::try_from_field_bytes(&[&[0; 4], &[0; 2]]);
+  assert_eq!(wrong_count.err(), Some(bytemuck::PodCastError::SizeMismatch));
+  let wrong_len =
+    FieldBytes::try_from_field_bytes(&[&[0; 4], &[0; 3], &[0; 2]]);
+  assert_eq!(wrong_len.err(), Some(bytemuck::PodCastError::SizeMismatch));
+
+  let value =
+    FieldBytesGeneric::<u16>::try_from_field_bytes(&[&[7], &[1, 1]]).unwrap();
+  assert_eq!({ value.0 }, 7);
+  assert_eq!({ value.1 }, 0x0101);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+#[bytemuck(columns)]
+struct Vertex {
+  pos: [f32; 3],
+  uv: [f32; 2],
+  id: u32,
+}
+
+#[derive(Copy, Clone, Pod, Zeroable)]
+#[repr(C, packed)]
+#[bytemuck(columns)]
+struct ColumnsGeneric<T: Pod>(u8, T);
+
+#[derive(Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+#[bytemuck(columns)]
+struct ColumnsEmpty {}
+
+#[test]
+fn split_columns() {
+  let vertices = [
+    Vertex { pos: [0.0, 1.0, 2.0], uv: [0.0, 0.5], id: 7 },
+    Vertex { pos: [3.0, 4.0, 5.0], uv: [1.0, 0.5], id: 8 },
+    Vertex { pos: [6.0, 7.0, 8.0], uv: [0.5, 1.0], id: 9 },
+  ];
+  let (positions, uvs, ids) = Vertex::split_columns(&vertices);
+  assert_eq!(positions, [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]);
+  assert_eq!(uvs, [[0.0, 0.5], [1.0, 0.5], [0.5, 1.0]]);
+  assert_eq!(ids, [7, 8, 9]);
+
+  // and back again.
+  let rebuilt: Vec<Vertex> = positions
+    .iter()
+    .zip(&uvs)
+    .zip(&ids)
+    .map(|((&pos, &uv), &id)| Vertex { pos, uv, id })
+    .collect();
+  assert_eq!(rebuilt, vertices);
+
+  let (positions, uvs, ids) = Vertex::split_columns(&[]);
+  assert!(positions.is_empty() && uvs.is_empty() && ids.is_empty());
+
+  let (tags, values) =
+    ColumnsGeneric::<u16>::split_columns(&[ColumnsGeneric(1, 2)]);
+  assert_eq!((tags, values), (vec![1], vec![2]));
+
+  let () = ColumnsEmpty::split_columns(&[ColumnsEmpty {}]);
+}
@@ -0,0 +1,11 @@
+//! Checks that the derives reject invalid inputs with a helpful error.
+//!
+//! The expected output lives next to each case in `tests/ui`. After a change
+//! to a diagnostic, regenerate it with `TRYBUILD=overwrite cargo test`.
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn derive_rejections() {
+  let t = trybuild::TestCases::new();
+  t.compile_fail("tests/ui/*.rs");
+}
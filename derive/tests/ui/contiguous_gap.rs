@@ -0,0 +1,11 @@
+use bytemuck::Contiguous;
+
+#[derive(Copy, Clone, Contiguous)]
+#[repr(u8)]
+enum Gap {
+  A = 0,
+  B = 1,
+  C = 3,
+}
+
+fn main() {}
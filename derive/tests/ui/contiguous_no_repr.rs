@@ -0,0 +1,9 @@
+use bytemuck::Contiguous;
+
+#[derive(Copy, Clone, Contiguous)]
+enum NoRepr {
+  A,
+  B,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bytemuck::Contiguous;
+
+#[derive(Copy, Clone, Contiguous)]
+#[repr(u8)]
+enum WithFields {
+  A(u8),
+  B,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bytemuck::Pod;
+
+#[derive(Copy, Clone, Pod)]
+#[repr(C)]
+enum Fieldless {
+  A = 0,
+  B = 1,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bytemuck::{Pod, Zeroable};
+
+#[derive(Copy, Clone, Pod, Zeroable)]
+struct NoRepr {
+  a: u32,
+  b: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use bytemuck::{Pod, Zeroable};
+
+#[derive(Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+struct Padded {
+  a: u8,
+  b: u32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use bytemuck::TransparentWrapper;
+
+#[derive(TransparentWrapper)]
+struct NotTransparent {
+  a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use bytemuck::TransparentWrapper;
+use core::marker::PhantomData;
+
+#[derive(TransparentWrapper)]
+#[repr(transparent)]
+#[transparent(u64)]
+struct WrongInner {
+  a: u32,
+  b: PhantomData<u8>,
+}
+
+fn main() {}
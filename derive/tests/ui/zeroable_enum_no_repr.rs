@@ -0,0 +1,9 @@
+use bytemuck::Zeroable;
+
+#[derive(Zeroable)]
+enum NoRepr {
+  A,
+  B,
+}
+
+fn main() {}
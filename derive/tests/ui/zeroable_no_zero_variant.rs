@@ -0,0 +1,10 @@
+use bytemuck::Zeroable;
+
+#[derive(Zeroable)]
+#[repr(u8)]
+enum NoZero {
+  A = 1,
+  B = 2,
+}
+
+fn main() {}
@@ -0,0 +1,118 @@
+use super::*;
+use core::ops::{Deref, DerefMut};
+
+/// Marks `$name<[$elem; N]>` as `Pod` for every element type and every total
+/// size (in bytes) given.
+macro_rules! impl_aligned_pod {
+  ($name:ident, $sizes:tt, $($elem:ty),*) => {
+    $(impl_aligned_pod!(@sizes $name, $elem, $sizes);)*
+  };
+  (@sizes $name:ident, $elem:ty, [$($bytes:literal),*]) => {
+    $(unsafe impl Pod for $name<[$elem; $bytes / size_of::<$elem>()]> {})*
+  };
+}
+
+macro_rules! impl_aligned_wrapper {
+  (
+    $(#[$attr:meta])* $name:ident, $align:literal, $sizes:tt
+  ) => {
+    $(#[$attr])*
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    #[repr(C, align($align))]
+    pub struct $name<T>(pub T);
+
+    unsafe impl<T: Zeroable> Zeroable for $name<T> {}
+
+    // Note: a wrapper around an inner type whose size isn't a multiple of the
+    // alignment would have trailing padding, so we can only mark the wrapper as
+    // `Pod` when the inner size is known to fill the whole thing.
+    impl_aligned_pod!(
+      $name, $sizes, u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64
+    );
+
+    impl<T> Deref for $name<T> {
+      type Target = T;
+
+      #[inline]
+      fn deref(&self) -> &T {
+        &self.0
+      }
+    }
+
+    impl<T> DerefMut for $name<T> {
+      #[inline]
+      fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+      }
+    }
+
+    impl<T> From<T> for $name<T> {
+      #[inline]
+      fn from(t: T) -> Self {
+        $name(t)
+      }
+    }
+  };
+}
+
+impl_aligned_wrapper!(
+  /// Wraps a `T` so that it's aligned to at least 16 bytes.
+  ///
+  /// Casting a slice of these to bytes gives a base pointer that's aligned to
+  /// 16, which makes them useful for building SIMD or DMA friendly buffers.
+  ///
+  /// This is always [`Zeroable`] when `T` is.
+  ///
+  /// ```rust
+  /// # use bytemuck::*;
+  /// let buf = [Align16([0_u8; 16]); 4];
+  /// let bytes: &[u8] = cast_slice(&buf);
+  /// assert_eq!(bytes.len(), 64);
+  /// assert_eq!(bytes.as_ptr() as usize % 16, 0);
+  /// ```
+  ///
+  /// ## Limitations
+  ///
+  /// This is **not** [`Pod`] whenever `T` is. Because the wrapper would have
+  /// trailing padding bytes if the size of `T` wasn't a multiple of 16, it's
+  /// only `Pod` for a fixed list of types: arrays of a primitive integer or
+  /// float type whose total size is 16, 32, 48, 64, 96, 128, or a larger power
+  /// of two up to 4096 bytes. For example `Align16<[u8; 16]>`,
+  /// `Align16<[f32; 4]>`, and `Align16<[u64; 8]>` are all `Pod`, but
+  /// `Align16<u32>`, `Align16<[u8; 20]>`, and `Align16<[[u8; 4]; 4]>` aren't.
+  Align16,
+  16,
+  [16, 32, 48, 64, 96, 128, 256, 512, 1024, 2048, 4096]
+);
+
+impl_aligned_wrapper!(
+  /// Wraps a `T` so that it's aligned to at least 32 bytes.
+  ///
+  /// As [`Align16`], but with an alignment of 32.
+  ///
+  /// ## Limitations
+  ///
+  /// This is **not** [`Pod`] whenever `T` is. It's only `Pod` for arrays of a
+  /// primitive integer or float type whose total size is 32, 64, 96, or a
+  /// larger power of two up to 4096 bytes, such as `Align32<[u8; 32]>` or
+  /// `Align32<[f32; 8]>`.
+  Align32,
+  32,
+  [32, 64, 96, 128, 256, 512, 1024, 2048, 4096]
+);
+
+impl_aligned_wrapper!(
+  /// Wraps a `T` so that it's aligned to at least 64 bytes.
+  ///
+  /// As [`Align16`], but with an alignment of 64.
+  ///
+  /// ## Limitations
+  ///
+  /// This is **not** [`Pod`] whenever `T` is. It's only `Pod` for arrays of a
+  /// primitive integer or float type whose total size is 64 or a larger power
+  /// of two up to 4096 bytes, such as `Align64<[u8; 64]>` or
+  /// `Align64<[f32; 16]>`.
+  Align64,
+  64,
+  [64, 128, 256, 512, 1024, 2048, 4096]
+);
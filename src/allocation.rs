@@ -27,6 +27,7 @@ use core::{
 
 /// As [`try_cast_box`], but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_box<A: NoUninit, B: AnyBitPattern>(input: Box<A>) -> Box<B> {
   try_cast_box(input).map_err(|(e, _v)| e).unwrap()
 }
@@ -55,6 +56,128 @@ pub fn try_cast_box<A: NoUninit, B: AnyBitPattern>(
   }
 }
 
+/// As [`try_realloc_cast_box`], but unwraps for you.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn realloc_cast_box<A: NoUninit, B: AnyBitPattern>(
+  input: Box<A>,
+) -> Box<B> {
+  try_realloc_cast_box(input).map_err(|(e, _v)| e).unwrap()
+}
+
+/// Casts the content type of a [`Box`], copying into a new allocation if the
+/// alignments don't match.
+///
+/// When the alignments match, this is the same as [`try_cast_box`] and the
+/// allocation is reused. Otherwise a new zeroed `Box<B>` is allocated with the
+/// alignment of `B`, the bytes are copied in, and the old box is freed. This
+/// removes the [`PodCastError::AlignmentMismatch`] case when you don't need a
+/// zero-copy cast.
+///
+/// On failure you get back an error along with the starting `Box`.
+///
+/// ## Failure
+///
+/// * The start and end size of the `Box` must have the exact same size.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let b: Box<[u8; 4]> = Box::new(7_u32.to_ne_bytes());
+/// let b: Box<u32> = try_realloc_cast_box(b).unwrap();
+/// assert_eq!(*b, 7);
+/// ```
+#[inline]
+pub fn try_realloc_cast_box<A: NoUninit, B: AnyBitPattern>(
+  input: Box<A>,
+) -> Result<Box<B>, (PodCastError, Box<A>)> {
+  if size_of::<A>() != size_of::<B>() {
+    Err((PodCastError::SizeMismatch, input))
+  } else if align_of::<A>() == align_of::<B>() {
+    try_cast_box(input)
+  } else {
+    let mut output: Box<B> = zeroed_box();
+    // Safety: the sizes match, and `B` is fine with any bit pattern.
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        &*input as *const A as *const u8,
+        &mut *output as *mut B as *mut u8,
+        size_of::<B>(),
+      )
+    };
+    Ok(output)
+  }
+}
+
+/// As [`try_vec_to_box`], but unwraps for you.
+///
+/// The version that gives back
+/// `Result<Box<T>, (PodCastError, Vec<u8>)>` is [`try_vec_to_box`]. This
+/// unprefixed name panics instead, matching the other box and vec casts
+/// ([`cast_box`] and [`try_cast_box`], [`cast_vec`] and [`try_cast_vec`]).
+///
+/// ## Panics
+///
+/// * If the length of the vec isn't exactly `size_of::<T>()`.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn vec_to_box<T: AnyBitPattern>(input: Vec<u8>) -> Box<T> {
+  try_vec_to_box(input).map_err(|(e, _v)| e).unwrap()
+}
+
+/// Attempts to turn a `Vec<u8>` holding exactly one `T` worth of bytes into a
+/// `Box<T>`.
+///
+/// The allocation of a byte vec has an alignment of 1, and a `Box<T>` must be
+/// freed with the same layout it was allocated with. So the vec's allocation
+/// is only reused when `T` also has an alignment of 1 and the vec's capacity is
+/// exactly its length. Otherwise a new zeroed box is allocated, the bytes are
+/// copied in, and the vec is dropped.
+///
+/// On failure you get back an error along with the starting `Vec`.
+///
+/// ## Failure
+///
+/// * The length of the vec must be exactly `size_of::<T>()`.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let bytes = 7_u32.to_ne_bytes().to_vec();
+/// let b: Box<u32> = try_vec_to_box(bytes).unwrap();
+/// assert_eq!(*b, 7);
+///
+/// let (err, bytes) = try_vec_to_box::<u32>(vec![1, 2, 3]).unwrap_err();
+/// assert_eq!(err, PodCastError::SizeMismatch);
+/// assert_eq!(bytes, [1, 2, 3]);
+/// ```
+#[inline]
+pub fn try_vec_to_box<T: AnyBitPattern>(
+  input: Vec<u8>,
+) -> Result<Box<T>, (PodCastError, Vec<u8>)> {
+  if input.len() != size_of::<T>() {
+    Err((PodCastError::SizeMismatch, input))
+  } else if align_of::<T>() == 1
+    && size_of::<T>() != 0
+    && input.capacity() == input.len()
+  {
+    // The vec was allocated with the exact layout of `T`, so the box can take
+    // over the allocation.
+    let mut input = ManuallyDrop::new(input);
+    let ptr: *mut T = input.as_mut_ptr() as *mut T;
+    Ok(unsafe { Box::from_raw(ptr) })
+  } else {
+    let mut output: Box<T> = zeroed_box();
+    // Safety: the lengths match, and `T` is fine with any bit pattern.
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        input.as_ptr(),
+        &mut *output as *mut T as *mut u8,
+        size_of::<T>(),
+      )
+    };
+    Ok(output)
+  }
+}
+
 /// Allocates a `Box<T>` with all of the contents being zeroed out.
 ///
 /// This uses the global allocator to create a zeroed allocation and _then_
@@ -149,6 +272,15 @@ pub fn zeroed_slice_box<T: Zeroable>(length: usize) -> Box<[T]> {
   try_zeroed_slice_box(length).unwrap()
 }
 
+/// Allocates a `Box<[u8]>` of `length` zeroed bytes.
+///
+/// This is [`zeroed_slice_box`] for bytes: the memory comes straight from a
+/// zeroed allocation, and a `length` of 0 doesn't allocate at all.
+#[inline]
+pub fn zeroed_bytes(length: usize) -> Box<[u8]> {
+  zeroed_slice_box(length)
+}
+
 /// Allocates a `Arc<T>` with all contents being zeroed out.
 #[cfg(all(feature = "alloc_uninit", target_has_atomic = "ptr"))]
 pub fn zeroed_arc<T: Zeroable>() -> Arc<T> {
@@ -183,6 +315,7 @@ pub fn zeroed_rc_slice<T: Zeroable>(length: usize) -> Rc<[T]> {
 
 /// As [`try_cast_slice_box`], but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_slice_box<A: NoUninit, B: AnyBitPattern>(
   input: Box<[A]>,
 ) -> Box<[B]> {
@@ -236,8 +369,55 @@ pub fn try_cast_slice_box<A: NoUninit, B: AnyBitPattern>(
   }
 }
 
+/// As [`try_boxed_array_cast`], but unwraps for you.
+#[cfg(feature = "min_const_generics")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "min_const_generics")))]
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn boxed_array_cast<A: NoUninit, B: AnyBitPattern, const N: usize>(
+  input: Box<[A; N]>,
+) -> Box<[B]> {
+  try_boxed_array_cast(input).map_err(|(e, _v)| e).unwrap()
+}
+
+/// Attempts to turn a `Box<[A; N]>` into a `Box<[B]>`, reusing the allocation.
+///
+/// This is the unsizing of the array into a slice followed by
+/// [`try_cast_slice_box`], with the original array box given back on failure.
+///
+/// ## Failure
+///
+/// * `A` and `B` must have the exact same alignment.
+/// * The `N` elements of `A` must be an exact multiple of the size of `B`.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let bytes: Box<[u8; 8]> = Box::new([1, 0, 0, 0, 2, 0, 0, 0]);
+/// let halves: Box<[[u8; 2]]> = try_boxed_array_cast(bytes).unwrap();
+/// assert_eq!(&*halves, &[[1, 0], [0, 0], [2, 0], [0, 0]][..]);
+///
+/// let words: Box<[u16; 2]> = Box::new([1, 2]);
+/// let (err, words) = try_boxed_array_cast::<_, u32, 2>(words).unwrap_err();
+/// assert_eq!(err, PodCastError::AlignmentMismatch);
+/// assert_eq!(*words, [1, 2]);
+/// ```
+#[cfg(feature = "min_const_generics")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "min_const_generics")))]
+#[inline]
+pub fn try_boxed_array_cast<A: NoUninit, B: AnyBitPattern, const N: usize>(
+  input: Box<[A; N]>,
+) -> Result<Box<[B]>, (PodCastError, Box<[A; N]>)> {
+  let slice: Box<[A]> = input;
+  try_cast_slice_box(slice).map_err(|(e, slice)| {
+    // The slice we got back is the one we made above, so it has length `N`.
+    let ptr = Box::into_raw(slice) as *mut [A; N];
+    (e, unsafe { Box::from_raw(ptr) })
+  })
+}
+
 /// As [`try_cast_vec`], but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_vec<A: NoUninit, B: AnyBitPattern>(input: Vec<A>) -> Vec<B> {
   try_cast_vec(input).map_err(|(e, _v)| e).unwrap()
 }
@@ -347,8 +527,371 @@ pub fn pod_collect_to_vec<A: NoUninit, B: NoUninit + AnyBitPattern>(
   dst
 }
 
+/// As [`try_cast_into_vec`], but unwraps for you.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn cast_into_vec<A: NoUninit, B: NoUninit + AnyBitPattern>(
+  src: &[A], out: &mut Vec<B>,
+) {
+  try_cast_into_vec(src, out).unwrap()
+}
+
+/// Copies a slice of pod data into an existing vec of a different pod type.
+///
+/// This is like [`pod_collect_to_vec`], but it reuses the allocation of `out`
+/// rather than making a new vec each time, which helps when casting in a loop.
+/// The vec is cleared first, and then holds exactly the bytes of `src`. Since
+/// the bytes are copied, alignment doesn't matter.
+///
+/// ## Failure
+///
+/// * If the bytes of `src` aren't a whole number of `B` this is
+///   [`PodCastError::OutputSliceWouldHaveSlop`], and `out` is left as it was.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let mut out: Vec<u16> = Vec::new();
+/// for chunk in [[1_u8, 0, 2, 0], [3, 0, 4, 0]].iter() {
+///   try_cast_into_vec(chunk, &mut out).unwrap();
+///   assert_eq!(out.len(), 2);
+/// }
+/// assert_eq!(out, [u16::from_ne_bytes([3, 0]), u16::from_ne_bytes([4, 0])]);
+/// ```
+#[inline]
+pub fn try_cast_into_vec<A: NoUninit, B: NoUninit + AnyBitPattern>(
+  src: &[A], out: &mut Vec<B>,
+) -> Result<(), PodCastError> {
+  let src_bytes: &[u8] = cast_slice(src);
+  let dst_count = if size_of::<B>() == 0 {
+    if !src_bytes.is_empty() {
+      return Err(PodCastError::OutputSliceWouldHaveSlop);
+    }
+    0
+  } else if src_bytes.len() % size_of::<B>() != 0 {
+    return Err(PodCastError::OutputSliceWouldHaveSlop);
+  } else {
+    src_bytes.len() / size_of::<B>()
+  };
+  out.clear();
+  out.resize(dst_count, B::zeroed());
+  let dst_bytes: &mut [u8] = cast_slice_mut(&mut out[..]);
+  dst_bytes.copy_from_slice(src_bytes);
+  Ok(())
+}
+
+/// As [`try_interleave`], but unwraps for you.
+///
+/// Note that this is the panicking form: the one that returns
+/// `Result<(), PodCastError>` on a length mismatch is [`try_interleave`], in
+/// keeping with the `try_` prefix used by every other fallible function here.
+///
+/// ## Panics
+///
+/// * If `a` and `b` don't have the same length.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn interleave<A: NoUninit, B: NoUninit>(
+  a: &[A], b: &[B], out: &mut Vec<u8>,
+) {
+  try_interleave(a, b, out).unwrap()
+}
+
+/// Appends the bytes of `a[0]`, `b[0]`, `a[1]`, `b[1]`, and so on to `out`.
+///
+/// This packs two separate streams of components into one interleaved buffer,
+/// such as positions and colors into a vertex buffer. The bytes are copied, so
+/// alignment doesn't matter. No padding is added between the elements, so the
+/// output matches a `#[repr(C)]` struct of an `A` and a `B` only when that
+/// struct has no padding.
+///
+/// ## Failure
+///
+/// * If `a` and `b` don't have the same length this is
+///   [`PodCastError::SizeMismatch`], and `out` is left as it was.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let mut out = Vec::new();
+/// try_interleave(&[1_u8, 2], &[[3_u8; 2], [4; 2]], &mut out).unwrap();
+/// assert_eq!(out, [1, 3, 3, 2, 4, 4]);
+/// ```
+#[inline]
+pub fn try_interleave<A: NoUninit, B: NoUninit>(
+  a: &[A], b: &[B], out: &mut Vec<u8>,
+) -> Result<(), PodCastError> {
+  if a.len() != b.len() {
+    return Err(PodCastError::SizeMismatch);
+  }
+  out.reserve(size_of_val(a) + size_of_val(b));
+  for (a, b) in a.iter().zip(b) {
+    out.extend_from_slice(bytes_of(a));
+    out.extend_from_slice(bytes_of(b));
+  }
+  Ok(())
+}
+
+/// As [`try_interleave3`], but unwraps for you.
+///
+/// ## Panics
+///
+/// * If `a`, `b`, and `c` don't all have the same length.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn interleave3<A: NoUninit, B: NoUninit, C: NoUninit>(
+  a: &[A], b: &[B], c: &[C], out: &mut Vec<u8>,
+) {
+  try_interleave3(a, b, c, out).unwrap()
+}
+
+/// As [`try_interleave`], but with three streams of components.
+///
+/// ## Failure
+///
+/// * If `a`, `b`, and `c` don't all have the same length this is
+///   [`PodCastError::SizeMismatch`], and `out` is left as it was.
+#[inline]
+pub fn try_interleave3<A: NoUninit, B: NoUninit, C: NoUninit>(
+  a: &[A], b: &[B], c: &[C], out: &mut Vec<u8>,
+) -> Result<(), PodCastError> {
+  if a.len() != b.len() || a.len() != c.len() {
+    return Err(PodCastError::SizeMismatch);
+  }
+  out.reserve(size_of_val(a) + size_of_val(b) + size_of_val(c));
+  for ((a, b), c) in a.iter().zip(b).zip(c) {
+    out.extend_from_slice(bytes_of(a));
+    out.extend_from_slice(bytes_of(b));
+    out.extend_from_slice(bytes_of(c));
+  }
+  Ok(())
+}
+
+/// Appends the number of elements in `slice` as a little-endian `u32`,
+/// followed by the bytes of the elements, to `out`.
+///
+/// Use [`read_length_prefixed`] to read it back. The element bytes are written
+/// as they are in memory, so only the prefix has a fixed byte order.
+///
+/// ## Panics
+///
+/// * If `slice` has more than `u32::MAX` elements. Use
+///   [`write_length_prefixed_u64`] for those.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let mut out = Vec::new();
+/// write_length_prefixed(&[1_u16, 2, 3], &mut out);
+/// assert_eq!(out.len(), 4 + 6);
+/// let (values, rest) = read_length_prefixed::<u16>(&out).unwrap();
+/// assert_eq!(values, [1, 2, 3]);
+/// assert!(rest.is_empty());
+/// ```
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn write_length_prefixed<T: NoUninit>(slice: &[T], out: &mut Vec<u8>) {
+  let count = slice.len() as u32;
+  assert!(count as usize == slice.len(), "too many elements for a u32 length");
+  out.extend_from_slice(&count.to_le_bytes());
+  out.extend_from_slice(cast_slice(slice));
+}
+
+/// As [`write_length_prefixed`], but with a `u64` prefix.
+#[inline]
+pub fn write_length_prefixed_u64<T: NoUninit>(slice: &[T], out: &mut Vec<u8>) {
+  out.extend_from_slice(&(slice.len() as u64).to_le_bytes());
+  out.extend_from_slice(cast_slice(slice));
+}
+
+/// Reads elements written by [`write_length_prefixed`] from the start of
+/// `bytes`.
+///
+/// The elements come right after the 4 byte prefix, so they usually aren't
+/// aligned for `T`. Rather than failing in that case, the elements are always
+/// copied into a new `Vec`. Any bytes after the elements are given back as
+/// well, so that more data can be read from there.
+///
+/// ## Failure
+///
+/// * If `bytes` is too short for the prefix, or for the number of elements it
+///   says there are, this is [`PodCastError::SizeMismatch`].
+#[inline]
+pub fn read_length_prefixed<T: AnyBitPattern>(
+  bytes: &[u8],
+) -> Result<(Vec<T>, &[u8]), PodCastError> {
+  let prefix: [u8; 4] = match bytes.get(..4) {
+    Some(prefix) => pod_read_unaligned(prefix),
+    None => return Err(PodCastError::SizeMismatch),
+  };
+  read_elements(u32::from_le_bytes(prefix) as u64, &bytes[4..])
+}
+
+/// As [`read_length_prefixed`], but for a `u64` prefix written by
+/// [`write_length_prefixed_u64`].
+#[inline]
+pub fn read_length_prefixed_u64<T: AnyBitPattern>(
+  bytes: &[u8],
+) -> Result<(Vec<T>, &[u8]), PodCastError> {
+  let prefix: [u8; 8] = match bytes.get(..8) {
+    Some(prefix) => pod_read_unaligned(prefix),
+    None => return Err(PodCastError::SizeMismatch),
+  };
+  read_elements(u64::from_le_bytes(prefix), &bytes[8..])
+}
+
+/// Copies `count` elements from the front of `bytes`, giving back the rest.
+fn read_elements<T: AnyBitPattern>(
+  prefix: u64, bytes: &[u8],
+) -> Result<(Vec<T>, &[u8]), PodCastError> {
+  let count = prefix as usize;
+  if count as u64 != prefix {
+    return Err(PodCastError::SizeMismatch);
+  }
+  let data_len = match count.checked_mul(size_of::<T>()) {
+    Some(data_len) if data_len <= bytes.len() => data_len,
+    _ => return Err(PodCastError::SizeMismatch),
+  };
+  let (data, rest) = bytes.split_at(data_len);
+  let mut values: Vec<T> = zeroed_vec(count);
+  // Safety: `values` holds exactly `data_len` bytes, and `T` is fine with any
+  // bit pattern.
+  unsafe {
+    core::ptr::copy_nonoverlapping(
+      data.as_ptr(),
+      values.as_mut_ptr() as *mut u8,
+      data_len,
+    )
+  };
+  Ok((values, rest))
+}
+
+/// Copies each element of a slice through `f` into a new `Vec`.
+///
+/// This is for casts that also need to change the representation of each
+/// element, such as swapping the byte order. Each element is passed to `f` by
+/// value, and the output vec is allocated once with room for all of them.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let big_endian = [0x0102_u16.to_be(), 0x0304_u16.to_be()];
+/// let native = cast_slice_map(&big_endian, u16::from_be);
+/// assert_eq!(native, [0x0102, 0x0304]);
+/// ```
+pub fn cast_slice_map<A: NoUninit, B: AnyBitPattern, F: FnMut(A) -> B>(
+  input: &[A], mut f: F,
+) -> Vec<B> {
+  let mut out = Vec::with_capacity(input.len());
+  out.extend(input.iter().map(|&a| f(a)));
+  out
+}
+
+/// Copies the bytes of each referenced value, in order, into one `Vec<u8>`.
+///
+/// This is for gathering values that are held by reference (and so aren't
+/// contiguous in memory) into a single buffer. It always copies. A ZST `T`
+/// contributes no bytes, so the output is empty.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let (a, b) = (1_u16, 2_u16);
+/// let bytes = gather_bytes(&[&a, &b, &a]);
+/// assert_eq!(bytes.len(), 6);
+/// assert_eq!(&bytes[2..4], bytes_of(&b));
+/// ```
+pub fn gather_bytes<T: NoUninit>(refs: &[&T]) -> Vec<u8> {
+  let mut out = Vec::with_capacity(refs.len() * size_of::<T>());
+  for t in refs {
+    out.extend_from_slice(bytes_of(*t));
+  }
+  out
+}
+
+/// As [`try_split_fields`], but unwraps for you.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn split_fields<T: NoUninit, A: AnyBitPattern, B: AnyBitPattern>(
+  src: &[T],
+) -> (Vec<A>, Vec<B>) {
+  try_split_fields(src).unwrap()
+}
+
+/// Splits a slice of two-part values into a `Vec` of each part.
+///
+/// Each `T` is treated as an `A` immediately followed by a `B`, which is the
+/// layout of a `#[repr(C)]` struct with an `A` field and then a `B` field and
+/// no padding. This is the usual "array of structs" to "struct of arrays"
+/// conversion.
+///
+/// The fields of neighboring elements aren't contiguous in memory, so there's
+/// no way to do this as a zero-copy cast. Instead every part is read by value,
+/// meaning that the alignment of `src` never matters.
+///
+/// ## Failure
+///
+/// * If `size_of::<T>()` isn't exactly `size_of::<A>() + size_of::<B>()`.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Pair {
+///   a: u32,
+///   b: u16,
+///   c: u16,
+/// }
+/// # unsafe impl Zeroable for Pair {}
+/// # unsafe impl Pod for Pair {}
+/// let pairs = [Pair { a: 1, b: 2, c: 3 }, Pair { a: 4, b: 5, c: 6 }];
+/// let (a, bc): (Vec<u32>, Vec<[u16; 2]>) = split_fields(&pairs);
+/// assert_eq!(a, [1, 4]);
+/// assert_eq!(bc, [[2, 3], [5, 6]]);
+/// ```
+pub fn try_split_fields<T: NoUninit, A: AnyBitPattern, B: AnyBitPattern>(
+  src: &[T],
+) -> Result<(Vec<A>, Vec<B>), PodCastError> {
+  if size_of::<T>() != size_of::<A>() + size_of::<B>() {
+    return Err(PodCastError::SizeMismatch);
+  }
+  let mut a_out = Vec::with_capacity(src.len());
+  let mut b_out = Vec::with_capacity(src.len());
+  for t in src {
+    let (a_bytes, b_bytes) = bytes_of(t).split_at(size_of::<A>());
+    a_out.push(pod_read_unaligned(a_bytes));
+    b_out.push(pod_read_unaligned(b_bytes));
+  }
+  Ok((a_out, b_out))
+}
+
+/// Creates a `Vec<T>` holding `count` copies of `value`.
+///
+/// This gives the same result as `vec![value; count]`, but when every byte of
+/// `value` is the same the fill is done as a single `memset` style write
+/// instead of element by element. In the all-zero case the allocation comes
+/// straight from the global allocator already zeroed (see [`zeroed_vec`]).
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let v: Vec<u32> = splat_vec(u32::MAX, 3);
+/// assert_eq!(v, [u32::MAX; 3]);
+/// ```
+pub fn splat_vec<T: Pod>(value: T, count: usize) -> Vec<T> {
+  match bytes_of(&value).split_first() {
+    Some((&0, rest)) if rest.iter().all(|&b| b == 0) => zeroed_vec(count),
+    Some((&first, rest)) if rest.iter().all(|&b| b == first) => {
+      let mut v: Vec<T> = Vec::with_capacity(count);
+      // `write_bytes` counts in units of `T`, so this writes exactly
+      // `count * size_of::<T>()` bytes into the spare capacity.
+      unsafe {
+        core::ptr::write_bytes(v.as_mut_ptr(), first, count);
+        v.set_len(count);
+      }
+      v
+    }
+    _ => vec![value; count],
+  }
+}
+
 /// As [`try_cast_rc`], but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_rc<A: NoUninit + AnyBitPattern, B: NoUninit + AnyBitPattern>(
   input: Rc<A>,
 ) -> Rc<B> {
@@ -385,6 +928,7 @@ pub fn try_cast_rc<A: NoUninit + AnyBitPattern, B: NoUninit + AnyBitPattern>(
 
 /// As [`try_cast_arc`], but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 #[cfg(target_has_atomic = "ptr")]
 pub fn cast_arc<A: NoUninit + AnyBitPattern, B: NoUninit + AnyBitPattern>(
   input: Arc<A>,
@@ -426,6 +970,7 @@ pub fn try_cast_arc<
 
 /// As [`try_cast_slice_rc`], but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_slice_rc<
   A: NoUninit + AnyBitPattern,
   B: NoUninit + AnyBitPattern,
@@ -492,6 +1037,7 @@ pub fn try_cast_slice_rc<
 
 /// As [`try_cast_slice_arc`], but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 #[cfg(target_has_atomic = "ptr")]
 pub fn cast_slice_arc<
   A: NoUninit + AnyBitPattern,
@@ -774,6 +1320,20 @@ impl<I: ?Sized, T: ?Sized + TransparentWrapper<I>> TransparentWrapperAlloc<I>
 {
 }
 
+impl<T: NoUninit> AsPodBytes for Vec<T> {
+  #[inline]
+  fn as_pod_bytes(&self) -> &[u8] {
+    cast_slice(self)
+  }
+}
+
+impl<T: NoUninit> AsPodBytes for Box<[T]> {
+  #[inline]
+  fn as_pod_bytes(&self) -> &[u8] {
+    cast_slice(self)
+  }
+}
+
 /// As `Box<[u8]>`, but remembers the original alignment.
 pub struct BoxBytes {
   // SAFETY: `ptr` is aligned to `layout.align()`, points to
@@ -978,3 +1538,112 @@ impl BoxBytes {
     self.layout
   }
 }
+
+/// A bump arena that hands out many typed [`Pod`] slices from one allocation.
+///
+/// The arena owns a single zeroed byte buffer of a fixed capacity, and each
+/// call to [`alloc_slice`](PodArena::alloc_slice) carves the next suitably
+/// aligned region out of it. Because slices are handed out from `&self`, many
+/// of them can be alive at the same time. Calling [`clear`](PodArena::clear)
+/// needs `&mut self`, so all of the slices must be gone before the space can be
+/// reused.
+///
+/// The arena never grows (growing would move the buffer out from under any
+/// slices that are still alive), so allocation just fails once it's full.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let arena = PodArena::with_capacity(64);
+/// let a: &mut [u8] = arena.alloc_slice(3).unwrap();
+/// let b: &mut [u32] = arena.alloc_slice(4).unwrap();
+/// a[0] = 1;
+/// b[0] = 2;
+/// assert_eq!(b.as_ptr() as usize % 4, 0);
+/// assert!(arena.alloc_slice::<u64>(100).is_none());
+/// ```
+pub struct PodArena {
+  // SAFETY: `ptr` is from `Box::<[u8]>::into_raw` with length `capacity`, and
+  // is owned by the arena. Bytes before `used` may be borrowed by slices that
+  // were handed out, bytes from `used` onward are never borrowed.
+  ptr: NonNull<u8>,
+  capacity: usize,
+  used: core::cell::Cell<usize>,
+}
+
+// SAFETY: The arena owns its buffer like a `Box<[u8]>` does. It is not `Sync`
+// because of the `Cell`.
+unsafe impl Send for PodArena {}
+
+impl PodArena {
+  /// Makes an arena with a zeroed buffer of `capacity` bytes.
+  pub fn with_capacity(capacity: usize) -> Self {
+    let buf: Box<[u8]> = vec![0_u8; capacity].into_boxed_slice();
+    // SAFETY: Box::into_raw() returns a non-null pointer.
+    let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(buf) as *mut u8) };
+    PodArena { ptr, capacity, used: core::cell::Cell::new(0) }
+  }
+
+  /// The total size of the arena's buffer, in bytes.
+  #[inline]
+  pub fn capacity(&self) -> usize {
+    self.capacity
+  }
+
+  /// How many bytes have been handed out so far, including any bytes skipped
+  /// over to align each slice.
+  #[inline]
+  pub fn used(&self) -> usize {
+    self.used.get()
+  }
+
+  /// Hands out a zeroed slice of `count` elements.
+  ///
+  /// The slice starts at the first offset after all previous slices where a
+  /// `T` is properly aligned.
+  ///
+  /// ## Failure
+  ///
+  /// * If there isn't enough space left in the arena this gives `None`. A
+  ///   failed allocation doesn't use up any space.
+  #[allow(clippy::mut_from_ref)]
+  pub fn alloc_slice<T: Pod>(&self, count: usize) -> Option<&mut [T]> {
+    if size_of::<T>() == 0 {
+      // SAFETY: a dangling pointer is valid for a slice of ZSTs.
+      let ptr = NonNull::<T>::dangling().as_ptr();
+      return Some(unsafe { core::slice::from_raw_parts_mut(ptr, count) });
+    }
+    let used = self.used.get();
+    let addr = (self.ptr.as_ptr() as usize).wrapping_add(used);
+    let padding = (align_of::<T>() - addr % align_of::<T>()) % align_of::<T>();
+    let start = used.checked_add(padding)?;
+    let len = count.checked_mul(size_of::<T>())?;
+    let end = start.checked_add(len)?;
+    if end > self.capacity {
+      return None;
+    }
+    self.used.set(end);
+    // SAFETY: `start..end` is in bounds and wasn't borrowed before, because it
+    // was at or after `used`. Moving `used` to `end` means it won't be handed
+    // out again until `clear`, which needs `&mut self`.
+    let bytes = unsafe {
+      core::slice::from_raw_parts_mut(self.ptr.as_ptr().add(start), len)
+    };
+    fill_zeroes(bytes);
+    Some(cast_slice_mut(bytes))
+  }
+
+  /// Resets the arena so that the whole buffer can be handed out again.
+  #[inline]
+  pub fn clear(&mut self) {
+    self.used.set(0);
+  }
+}
+
+impl Drop for PodArena {
+  fn drop(&mut self) {
+    let slice =
+      core::ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), self.capacity);
+    // SAFETY: See type invariant.
+    drop(unsafe { Box::from_raw(slice) });
+  }
+}
@@ -0,0 +1,36 @@
+use super::*;
+
+/// Data that can be viewed as bytes, whether it's a single value, a slice, or
+/// (with the `extern_crate_alloc` feature) an owned buffer.
+///
+/// This lets a function that only needs the bytes of its argument take any of
+/// those, rather than having a separate version for each.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// fn byte_sum<D: AsPodBytes + ?Sized>(data: &D) -> u32 {
+///   data.as_pod_bytes().iter().map(|&b| u32::from(b)).sum()
+/// }
+///
+/// assert_eq!(byte_sum(&[1_u8, 2, 3]), 6);
+/// assert_eq!(byte_sum(&[1_u8, 2, 3][..]), 6);
+/// assert_eq!(byte_sum(&0x0101_u16), 2);
+/// ```
+pub trait AsPodBytes {
+  /// The bytes of this data.
+  fn as_pod_bytes(&self) -> &[u8];
+}
+
+impl<T: NoUninit> AsPodBytes for T {
+  #[inline]
+  fn as_pod_bytes(&self) -> &[u8] {
+    bytes_of(self)
+  }
+}
+
+impl<T: NoUninit> AsPodBytes for [T] {
+  #[inline]
+  fn as_pod_bytes(&self) -> &[u8] {
+    cast_slice(self)
+  }
+}
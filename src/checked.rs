@@ -411,6 +411,115 @@ pub fn try_cast_slice_mut<
   }
 }
 
+/// Try to turn a primitive integer into the matching `NonZero` type.
+///
+/// `N` must have exactly `T` as its [`Bits`](CheckedBitPattern::Bits), so
+/// this works for each of the `core::num::NonZero*` types from its own
+/// primitive (`u32` into `NonZeroU32`, and so on), but a `u32` can't be turned
+/// into a `NonZeroI32` by mistake.
+///
+/// ```rust
+/// # use bytemuck::checked::{self, CheckedCastError};
+/// # use core::num::NonZeroU16;
+/// let handle: NonZeroU16 = checked::try_into_nonzero(7_u16).unwrap();
+/// assert_eq!(handle.get(), 7);
+///
+/// let err = checked::try_into_nonzero::<u16, NonZeroU16>(0).unwrap_err();
+/// assert_eq!(err, CheckedCastError::InvalidBitPattern);
+/// ```
+///
+/// ## Failure
+///
+/// * If `value` is zero.
+#[inline]
+pub fn try_into_nonzero<T: AnyBitPattern, N: CheckedBitPattern<Bits = T>>(
+  value: T,
+) -> Result<N, CheckedCastError> {
+  if <N as CheckedBitPattern>::is_valid_bit_pattern(&value) {
+    Ok(unsafe { transmute!(value) })
+  } else {
+    Err(CheckedCastError::InvalidBitPattern)
+  }
+}
+
+/// Casts each element of a slice by value, checking each one separately.
+///
+/// Unlike [`try_cast_slice`], an invalid element doesn't fail the whole slice.
+/// Each element gives its own result, and an error comes with the index of
+/// the element in `src`, so that every failure can be handled on its own.
+/// Elements are copied out one at a time, so alignment isn't a factor.
+///
+/// ## Failure
+///
+/// * If the types don't have the same size, every element fails.
+/// * If an element contains an invalid bit pattern for `B`, that element
+///   fails.
+///
+/// ```rust
+/// # use bytemuck::checked::{self, CheckedCastError};
+/// let results: Vec<_> = checked::try_cast_each::<u8, bool>(&[1, 7, 0]).collect();
+/// assert_eq!(
+///   results,
+///   [Ok(true), Err((1, CheckedCastError::InvalidBitPattern)), Ok(false)]
+/// );
+/// ```
+#[inline]
+pub fn try_cast_each<'a, A: NoUninit, B: CheckedBitPattern + 'a>(
+  src: &'a [A],
+) -> impl Iterator<Item = Result<B, (usize, CheckedCastError)>> + 'a {
+  src.iter().enumerate().map(|(i, &a)| try_cast(a).map_err(|e| (i, e)))
+}
+
+/// Checks every element of bytes holding a slice of `B`, and gives the index of
+/// each invalid one.
+///
+/// Unlike [`try_cast_slice`], this doesn't stop at the first invalid element,
+/// so a tool can report all of the corruption in a buffer in one pass. Each
+/// element is read unaligned, so `bytes` can be at any alignment.
+///
+/// If the length of `bytes` isn't a whole number of `B`, the partial element
+/// at the end is also reported as invalid, with the index it would have had.
+///
+/// ```rust
+/// # use bytemuck::checked;
+/// assert_eq!(checked::validate_slice_all::<bool>(&[0, 1, 1, 0]), Ok(()));
+/// assert_eq!(
+///   checked::validate_slice_all::<bool>(&[0, 2, 1, 7]),
+///   Err(vec![1, 3])
+/// );
+/// ```
+#[cfg(feature = "extern_crate_alloc")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "extern_crate_alloc")))]
+pub fn validate_slice_all<B: CheckedBitPattern>(
+  bytes: &[u8],
+) -> Result<(), alloc::vec::Vec<usize>> {
+  let size = core::mem::size_of::<B>();
+  let mut invalid = alloc::vec::Vec::new();
+  if size == 0 {
+    if !bytes.is_empty() {
+      invalid.push(0);
+    }
+  } else {
+    let chunks = bytes.chunks_exact(size);
+    let whole = chunks.len();
+    let has_slop = !chunks.remainder().is_empty();
+    for (i, chunk) in chunks.enumerate() {
+      let bits = crate::pod_read_unaligned::<B::Bits>(chunk);
+      if !<B as CheckedBitPattern>::is_valid_bit_pattern(&bits) {
+        invalid.push(i);
+      }
+    }
+    if has_slop {
+      invalid.push(whole);
+    }
+  }
+  if invalid.is_empty() {
+    Ok(())
+  } else {
+    Err(invalid)
+  }
+}
+
 /// Re-interprets `&[u8]` as `&T`.
 ///
 /// ## Panics
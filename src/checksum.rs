@@ -0,0 +1,51 @@
+use super::*;
+
+/// The CRC-32 of some bytes, as used by zlib, PNG, and Ethernet.
+///
+/// This works a bit at a time rather than with a lookup table, which keeps the
+/// code small at the cost of speed. It's meant for header-sized data.
+fn crc32(bytes: &[u8]) -> u32 {
+  let mut crc = !0_u32;
+  for &byte in bytes {
+    crc ^= u32::from(byte);
+    for _ in 0..8 {
+      let mask = (crc & 1).wrapping_neg();
+      crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+    }
+  }
+  !crc
+}
+
+/// Computes a checksum over the bytes of a value.
+///
+/// The checksum is the standard CRC-32 (the one used by zlib, PNG, and
+/// Ethernet), so it can be checked by other tools. The result depends on the
+/// exact bytes of the value, and so on the endianness of any multi-byte
+/// fields.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// assert_eq!(pod_checksum(b"123456789"), 0xCBF4_3926);
+/// ```
+#[inline]
+pub fn pod_checksum<T: NoUninit>(t: &T) -> u32 {
+  crc32(bytes_of(t))
+}
+
+/// As [`pod_checksum`], but over all the bytes of a slice.
+#[inline]
+pub fn pod_checksum_slice<T: NoUninit>(s: &[T]) -> u32 {
+  crc32(cast_slice(s))
+}
+
+/// Checks that [`pod_checksum`] of a value is `expected`.
+#[inline]
+pub fn verify_pod_checksum<T: NoUninit>(t: &T, expected: u32) -> bool {
+  pod_checksum(t) == expected
+}
+
+/// Checks that [`pod_checksum_slice`] of a slice is `expected`.
+#[inline]
+pub fn verify_pod_checksum_slice<T: NoUninit>(s: &[T], expected: u32) -> bool {
+  pod_checksum_slice(s) == expected
+}
@@ -170,6 +170,72 @@ pub unsafe trait Contiguous: Copy + 'static {
   }
 }
 
+/// The error from [`parse_contiguous`]: the value that was out of range, along
+/// with the range that would have been valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContiguousError<I> {
+  /// The integer that didn't match any instance of the type.
+  pub value: I,
+  /// The lower *inclusive* bound of the valid range.
+  pub min: I,
+  /// The upper *inclusive* bound of the valid range.
+  pub max: I,
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<I: core::fmt::Display> core::fmt::Display for ContiguousError<I> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "got {}, valid range {}..={}", self.value, self.min, self.max)
+  }
+}
+#[cfg(feature = "extern_crate_std")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "extern_crate_std")))]
+impl<I: core::fmt::Debug + core::fmt::Display> std::error::Error
+  for ContiguousError<I>
+{
+}
+
+// Rust 1.81+
+#[cfg(all(feature = "impl_core_error", not(feature = "extern_crate_std")))]
+impl<I: core::fmt::Debug + core::fmt::Display> core::error::Error
+  for ContiguousError<I>
+{
+}
+
+/// As [`Contiguous::from_integer`], but on failure the error says which value
+/// was given and which range was valid.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// #[repr(u8)]
+/// #[derive(Debug, Copy, Clone, PartialEq)]
+/// enum Level {
+///   Low = 1,
+///   Mid = 2,
+///   High = 3,
+/// }
+/// unsafe impl Contiguous for Level {
+///   type Int = u8;
+///   const MIN_VALUE: u8 = Level::Low as u8;
+///   const MAX_VALUE: u8 = Level::High as u8;
+/// }
+///
+/// assert_eq!(parse_contiguous::<Level>(2), Ok(Level::Mid));
+/// let err = parse_contiguous::<Level>(7).unwrap_err();
+/// assert_eq!(err.to_string(), "got 7, valid range 1..=3");
+/// ```
+#[inline]
+pub fn parse_contiguous<E: Contiguous>(
+  value: E::Int,
+) -> Result<E, ContiguousError<E::Int>> {
+  match E::from_integer(value) {
+    Some(e) => Ok(e),
+    None => {
+      Err(ContiguousError { value, min: E::MIN_VALUE, max: E::MAX_VALUE })
+    }
+  }
+}
+
 macro_rules! impl_contiguous {
   ($($src:ty as $repr:ident in [$min:expr, $max:expr];)*) => {$(
     unsafe impl Contiguous for $src {
@@ -0,0 +1,68 @@
+use super::*;
+
+/// Writes a sequence of [`NoUninit`] values into a byte buffer, one after the
+/// other.
+///
+/// Each value is written at the current position without any regard for
+/// alignment, and then the position moves forward by the size of the value.
+/// This avoids tracking offsets by hand when serializing a heterogeneous
+/// sequence of values into a fixed buffer.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let mut buf = [0_u8; 8];
+/// let mut cursor = PodCursor::new(&mut buf);
+/// cursor.push(1_u8).unwrap();
+/// cursor.push(2_u32).unwrap();
+/// assert_eq!(cursor.written().len(), 5);
+/// assert_eq!(cursor.push(3_u64), Err(PodCastError::SizeMismatch));
+/// ```
+#[derive(Debug)]
+pub struct PodCursor<'a> {
+  buf: &'a mut [u8],
+  pos: usize,
+}
+
+impl<'a> PodCursor<'a> {
+  /// Makes a cursor that starts writing at the beginning of `buf`.
+  #[inline]
+  pub fn new(buf: &'a mut [u8]) -> Self {
+    PodCursor { buf, pos: 0 }
+  }
+
+  /// Writes the bytes of `value` at the current position and moves past them.
+  ///
+  /// ## Failure
+  ///
+  /// * If there isn't room left for a whole `T` this gives
+  ///   [`PodCastError::SizeMismatch`], and nothing is written.
+  #[inline]
+  pub fn push<T: NoUninit>(&mut self, value: T) -> Result<(), PodCastError> {
+    let bytes = bytes_of(&value);
+    if bytes.len() > self.remaining() {
+      return Err(PodCastError::SizeMismatch);
+    }
+    let end = self.pos + bytes.len();
+    self.buf[self.pos..end].copy_from_slice(bytes);
+    self.pos = end;
+    Ok(())
+  }
+
+  /// The bytes that have been written so far.
+  #[inline]
+  pub fn written(&self) -> &[u8] {
+    &self.buf[..self.pos]
+  }
+
+  /// How many bytes have been written so far.
+  #[inline]
+  pub fn position(&self) -> usize {
+    self.pos
+  }
+
+  /// How many bytes are still available for writing.
+  #[inline]
+  pub fn remaining(&self) -> usize {
+    self.buf.len() - self.pos
+  }
+}
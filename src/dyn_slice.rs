@@ -0,0 +1,98 @@
+use super::*;
+
+/// A view of bytes as a slice of elements whose size is only known at runtime.
+///
+/// This is made with [`dyn_cast_slice`], and each element is given back as its
+/// bytes, for the caller to interpret however the format requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynSlice<'a> {
+  bytes: &'a [u8],
+  elem_size: usize,
+}
+
+impl<'a> DynSlice<'a> {
+  /// The number of elements.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.bytes.len().checked_div(self.elem_size).unwrap_or(0)
+  }
+
+  /// If there are no elements.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// The size in bytes of each element.
+  #[inline]
+  pub fn elem_size(&self) -> usize {
+    self.elem_size
+  }
+
+  /// The bytes of element `index`, or `None` if it's out of bounds.
+  #[inline]
+  pub fn get(&self, index: usize) -> Option<&'a [u8]> {
+    if index < self.len() {
+      let start = index * self.elem_size;
+      Some(&self.bytes[start..start + self.elem_size])
+    } else {
+      None
+    }
+  }
+
+  /// All of the bytes being viewed.
+  #[inline]
+  pub fn as_bytes(&self) -> &'a [u8] {
+    self.bytes
+  }
+}
+
+/// Views `bytes` as a slice of elements with a size and alignment that are
+/// only known at runtime.
+///
+/// This is the dynamic version of [`try_cast_slice`] from bytes, for things
+/// like format interpreters where the element type isn't known until the data
+/// is read. The same rules apply, just using `elem_size` and `elem_align` in
+/// place of `size_of::<T>()` and `align_of::<T>()`.
+///
+/// ## Failure
+///
+/// * If the start of `bytes` isn't aligned to `elem_align` this is
+///   [`PodCastError::TargetAlignmentGreaterAndInputNotAligned`].
+/// * If `bytes` isn't a whole number of elements, including when `elem_size`
+///   is 0 and `bytes` isn't empty, this is
+///   [`PodCastError::OutputSliceWouldHaveSlop`].
+///
+/// ## Panics
+///
+/// * If `elem_align` isn't a power of two.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let bytes = [1_u8, 2, 3, 4, 5, 6];
+/// let elems = dyn_cast_slice(&bytes, 3, 1).unwrap();
+/// assert_eq!(elems.len(), 2);
+/// assert_eq!(elems.get(1), Some(&[4, 5, 6][..]));
+/// assert_eq!(elems.get(2), None);
+///
+/// assert_eq!(
+///   dyn_cast_slice(&bytes, 4, 1),
+///   Err(PodCastError::OutputSliceWouldHaveSlop)
+/// );
+/// ```
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn dyn_cast_slice(
+  bytes: &[u8], elem_size: usize, elem_align: usize,
+) -> Result<DynSlice<'_>, PodCastError> {
+  assert!(elem_align.is_power_of_two(), "elem_align must be a power of two");
+  if !internal::is_aligned_to(bytes.as_ptr() as *const (), elem_align) {
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  } else if (elem_size == 0 && !bytes.is_empty())
+    || (elem_size != 0 && bytes.len() % elem_size != 0)
+  {
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  } else {
+    Ok(DynSlice { bytes, elem_size })
+  }
+}
@@ -0,0 +1,98 @@
+use super::*;
+
+/// Marker trait for types whose bytes are the same on any target endianness.
+///
+/// Casting around bytes is normally _endian dependant_ (see the reminder on
+/// [`Pod`](crate::Pod)), but some types are made entirely of single-byte
+/// elements, and so their byte representation can't change between
+/// little-endian and big-endian machines. Generic serialization code can
+/// require this trait to statically know that no byte-swapping is ever needed
+/// for a type. [`as_le_bytes`] and [`try_from_le_bytes`] use it to skip the
+/// swap entirely.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// fn write_as_is<T: EndianAgnostic + NoUninit>(t: &T, out: &mut [u8]) {
+///   out.copy_from_slice(bytes_of(t));
+/// }
+/// let mut out = [0_u8; 4];
+/// write_as_is(b"ABCD", &mut out);
+/// assert_eq!(&out, b"ABCD");
+/// ```
+///
+/// ## Safety
+///
+/// * The in-memory byte representation of every value of the type must be the
+///   same regardless of the target's endianness.
+/// * In practice this means that the type is built only out of single-byte
+///   primitives (or arrays and slices of them), with no multi-byte integers,
+///   floats, or pointers anywhere inside.
+pub unsafe trait EndianAgnostic {}
+
+unsafe impl EndianAgnostic for u8 {}
+unsafe impl EndianAgnostic for i8 {}
+
+unsafe impl<T: EndianAgnostic> EndianAgnostic for [T] {}
+
+#[cfg(feature = "min_const_generics")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "min_const_generics")))]
+unsafe impl<T, const N: usize> EndianAgnostic for [T; N] where T: EndianAgnostic {}
+
+#[cfg(not(feature = "min_const_generics"))]
+impl_unsafe_marker_for_array!(
+  EndianAgnostic, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+  18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 48, 64, 96, 128,
+  256, 512, 1024, 2048, 4096
+);
+
+/// Gives the little-endian bytes of a slice.
+///
+/// Because `T` is [`EndianAgnostic`], its native bytes already are its
+/// little-endian (and big-endian) bytes on every target. So this returns the
+/// bytes of the slice as-is, without swapping or copying anything.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let tags = [*b"RIFF", *b"WAVE"];
+/// assert_eq!(as_le_bytes(&tags), b"RIFFWAVE");
+/// ```
+#[inline]
+pub fn as_le_bytes<T: EndianAgnostic + NoUninit>(s: &[T]) -> &[u8] {
+  cast_slice(s)
+}
+
+/// Reads a slice of `T` from little-endian bytes.
+///
+/// As with [`as_le_bytes`], an [`EndianAgnostic`] type never needs swapping,
+/// so this borrows `bytes` as-is.
+///
+/// ## Failure
+///
+/// * The same as [`try_cast_slice`].
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let tags: &[[u8; 4]] = try_from_le_bytes(b"RIFFWAVE").unwrap();
+/// assert_eq!(tags, [*b"RIFF", *b"WAVE"]);
+/// assert!(try_from_le_bytes::<[u8; 4]>(b"RIFF!").is_err());
+/// ```
+#[inline]
+pub fn try_from_le_bytes<T: EndianAgnostic + AnyBitPattern>(
+  bytes: &[u8],
+) -> Result<&[T], PodCastError> {
+  try_cast_slice(bytes)
+}
+
+/// Reads a slice of `T` from little-endian bytes.
+///
+/// ## Panics
+///
+/// This is [`try_from_le_bytes`] but will panic on error.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn from_le_bytes<T: EndianAgnostic + AnyBitPattern>(bytes: &[u8]) -> &[T] {
+  match try_from_le_bytes(bytes) {
+    Ok(t) => t,
+    Err(e) => internal::something_went_wrong("from_le_bytes", e),
+  }
+}
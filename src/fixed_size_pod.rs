@@ -0,0 +1,65 @@
+use core::{marker::PhantomData, num::Wrapping};
+
+/// Marker trait for types that have the same size on every target.
+///
+/// Types like `usize`, `isize`, and raw pointers are [`Pod`](crate::Pod), but
+/// their size depends on the target's pointer width. Serializing one of them
+/// gives output of a different length on a 32-bit target than on a 64-bit
+/// target. Format code can require this trait to reject those types at
+/// compile time.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// fn write<T: FixedSizePod + NoUninit>(t: &T, out: &mut Vec<u8>) {
+///   out.extend_from_slice(bytes_of(t));
+/// }
+/// let mut out = Vec::new();
+/// write(&1_u32, &mut out);
+/// write(&[2_u16; 3], &mut out);
+/// assert_eq!(out.len(), 10);
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use bytemuck::*;
+/// # fn write<T: FixedSizePod + NoUninit>(t: &T, out: &mut Vec<u8>) {
+/// #   out.extend_from_slice(bytes_of(t));
+/// # }
+/// let mut out = Vec::new();
+/// // the size of usize isn't fixed
+/// write(&1_usize, &mut out);
+/// ```
+///
+/// ## Safety
+///
+/// * The size of the type must be the same on every target.
+/// * In practice this means that the type is built only out of fixed-width
+///   integers and floats (or arrays of them), with no `usize`, `isize`, or
+///   pointers anywhere inside.
+pub unsafe trait FixedSizePod {}
+
+unsafe impl FixedSizePod for () {}
+unsafe impl FixedSizePod for u8 {}
+unsafe impl FixedSizePod for i8 {}
+unsafe impl FixedSizePod for u16 {}
+unsafe impl FixedSizePod for i16 {}
+unsafe impl FixedSizePod for u32 {}
+unsafe impl FixedSizePod for i32 {}
+unsafe impl FixedSizePod for u64 {}
+unsafe impl FixedSizePod for i64 {}
+unsafe impl FixedSizePod for u128 {}
+unsafe impl FixedSizePod for i128 {}
+unsafe impl FixedSizePod for f32 {}
+unsafe impl FixedSizePod for f64 {}
+unsafe impl<T: FixedSizePod> FixedSizePod for Wrapping<T> {}
+unsafe impl<T: ?Sized> FixedSizePod for PhantomData<T> {}
+
+#[cfg(feature = "min_const_generics")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "min_const_generics")))]
+unsafe impl<T, const N: usize> FixedSizePod for [T; N] where T: FixedSizePod {}
+
+#[cfg(not(feature = "min_const_generics"))]
+impl_unsafe_marker_for_array!(
+  FixedSizePod, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+  18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 48, 64, 96, 128,
+  256, 512, 1024, 2048, 4096
+);
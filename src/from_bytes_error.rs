@@ -0,0 +1,102 @@
+use super::*;
+
+/// The things that can go wrong with [`try_from_bytes_detailed`], with more
+/// detail about size problems than [`PodCastError::SizeMismatch`] gives.
+///
+/// This lets a streaming parser tell apart input that's too short, where it
+/// can wait for more data, from input that's too long, which is a real error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FromBytesError {
+  /// There were fewer bytes than the size of the type.
+  TooShort {
+    /// The size of the type.
+    needed: usize,
+    /// The number of bytes given.
+    got: usize,
+  },
+  /// There were more bytes than the size of the type.
+  TooLong {
+    /// The size of the type.
+    expected: usize,
+    /// The number of bytes given.
+    got: usize,
+  },
+  /// The size was right, but the cast failed for another reason, such as the
+  /// bytes not being aligned for the type.
+  PodCastError(PodCastError),
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::fmt::Display for FromBytesError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
+#[cfg(feature = "extern_crate_std")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "extern_crate_std")))]
+impl std::error::Error for FromBytesError {}
+
+// Rust 1.81+
+#[cfg(all(feature = "impl_core_error", not(feature = "extern_crate_std")))]
+impl core::error::Error for FromBytesError {}
+
+impl From<PodCastError> for FromBytesError {
+  fn from(err: PodCastError) -> FromBytesError {
+    FromBytesError::PodCastError(err)
+  }
+}
+
+#[inline]
+fn check_len<T>(len: usize) -> Result<(), FromBytesError> {
+  let size = size_of::<T>();
+  if len < size {
+    Err(FromBytesError::TooShort { needed: size, got: len })
+  } else if len > size {
+    Err(FromBytesError::TooLong { expected: size, got: len })
+  } else {
+    Ok(())
+  }
+}
+
+/// Re-interprets `&[u8]` as `&T`, with a detailed error on failure.
+///
+/// This is the same as [`try_from_bytes`], except that instead of
+/// [`PodCastError::SizeMismatch`] a wrong length is given as
+/// [`FromBytesError::TooShort`] or [`FromBytesError::TooLong`].
+///
+/// ## Failure
+///
+/// * If the slice is shorter or longer than the size of `T`.
+/// * If the slice isn't aligned for the new type, this is
+///   [`FromBytesError::PodCastError`].
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let buf = [0_u32; 2];
+/// let bytes: &[u8] = cast_slice(&buf);
+/// assert_eq!(try_from_bytes_detailed::<u32>(&bytes[..4]), Ok(&0));
+/// assert_eq!(
+///   try_from_bytes_detailed::<u32>(&bytes[..3]),
+///   Err(FromBytesError::TooShort { needed: 4, got: 3 })
+/// );
+/// assert_eq!(
+///   try_from_bytes_detailed::<u32>(bytes),
+///   Err(FromBytesError::TooLong { expected: 4, got: 8 })
+/// );
+/// ```
+#[inline]
+pub fn try_from_bytes_detailed<T: AnyBitPattern>(
+  s: &[u8],
+) -> Result<&T, FromBytesError> {
+  check_len::<T>(s.len())?;
+  Ok(try_from_bytes(s)?)
+}
+
+/// As [`try_from_bytes_detailed`], but `mut`.
+#[inline]
+pub fn try_from_bytes_mut_detailed<T: NoUninit + AnyBitPattern>(
+  s: &mut [u8],
+) -> Result<&mut T, FromBytesError> {
+  check_len::<T>(s.len())?;
+  Ok(try_from_bytes_mut(s)?)
+}
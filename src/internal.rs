@@ -70,6 +70,42 @@ pub(crate) unsafe fn bytes_of_mut<T: Copy>(t: &mut T) -> &mut [u8] {
   }
 }
 
+/// Finds the largest run of `vals` that can be viewed as a non-empty `[U]`.
+///
+/// Gives back the number of `T` before that run, and the number of `T` in it.
+#[inline]
+pub(crate) fn align_to_split<T, U>(
+  vals: &[T],
+) -> Result<(usize, usize), PodCastError> {
+  if size_of::<T>() == 0 || size_of::<U>() == 0 {
+    return Err(PodCastError::SizeMismatch);
+  }
+  // Stepping by one `T` at a time, the offset from the alignment of `U` repeats
+  // after at most `align_of::<U>()` steps, so that's as far as we need to look.
+  let head = match (0..vals.len().min(align_of::<U>()))
+    .find(|&i| is_aligned_to(vals[i..].as_ptr() as *const (), align_of::<U>()))
+  {
+    Some(head) => head,
+    None => return Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned),
+  };
+  // The middle has to end on the boundary of both a `T` and a `U`, so it's a
+  // whole number of chunks of their least common multiple in size.
+  let mut a = size_of::<T>();
+  let mut b = size_of::<U>();
+  while b != 0 {
+    let r = a % b;
+    a = b;
+    b = r;
+  }
+  let chunk_elems = size_of::<U>() / a;
+  let middle = (vals.len() - head) / chunk_elems * chunk_elems;
+  if middle == 0 {
+    Err(PodCastError::SizeMismatch)
+  } else {
+    Ok((head, middle))
+  }
+}
+
 /// Re-interprets `&[u8]` as `&T`.
 ///
 /// ## Panics
@@ -185,6 +221,71 @@ pub(crate) unsafe fn try_from_bytes_mut<T: Copy>(
   }
 }
 
+/// Re-interprets the `size_of::<T>()` bytes starting at `offset` as `&T`.
+///
+/// ## Failure
+///
+/// * If the requested range doesn't fit within the slice
+/// * If the sub-slice isn't aligned for the new type
+#[inline]
+pub(crate) unsafe fn try_from_bytes_at<T: Copy>(
+  s: &[u8], offset: usize,
+) -> Result<&T, PodCastError> {
+  match offset.checked_add(size_of::<T>()).and_then(|end| s.get(offset..end)) {
+    Some(sub) => try_from_bytes(sub),
+    None => Err(PodCastError::SizeMismatch),
+  }
+}
+
+/// Re-interprets the `size_of::<T>()` bytes starting at `offset` as `&mut T`.
+///
+/// ## Failure
+///
+/// * If the requested range doesn't fit within the slice
+/// * If the sub-slice isn't aligned for the new type
+#[inline]
+pub(crate) unsafe fn try_from_bytes_at_mut<T: Copy>(
+  s: &mut [u8], offset: usize,
+) -> Result<&mut T, PodCastError> {
+  match offset
+    .checked_add(size_of::<T>())
+    .and_then(move |end| s.get_mut(offset..end))
+  {
+    Some(sub) => try_from_bytes_mut(sub),
+    None => Err(PodCastError::SizeMismatch),
+  }
+}
+
+/// Re-interprets the `size_of::<T>()` bytes starting at `offset` as `&T`.
+///
+/// ## Panics
+///
+/// This is [`try_from_bytes_at`] but will panic on error.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub(crate) unsafe fn from_bytes_at<T: Copy>(s: &[u8], offset: usize) -> &T {
+  match try_from_bytes_at(s, offset) {
+    Ok(t) => t,
+    Err(e) => something_went_wrong("from_bytes_at", e),
+  }
+}
+
+/// Re-interprets the `size_of::<T>()` bytes starting at `offset` as `&mut T`.
+///
+/// ## Panics
+///
+/// This is [`try_from_bytes_at_mut`] but will panic on error.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub(crate) unsafe fn from_bytes_at_mut<T: Copy>(
+  s: &mut [u8], offset: usize,
+) -> &mut T {
+  match try_from_bytes_at_mut(s, offset) {
+    Ok(t) => t,
+    Err(e) => something_went_wrong("from_bytes_at_mut", e),
+  }
+}
+
 /// Cast `A` into `B`
 ///
 /// ## Panics
@@ -404,3 +505,35 @@ pub(crate) unsafe fn try_cast_slice_mut<A: Copy, B: Copy>(
     Err(PodCastError::OutputSliceWouldHaveSlop)
   }
 }
+
+/// Try to convert `&[A]` into `&[B]` with exactly `expected_len` elements.
+///
+/// ## Failure
+///
+/// * All the ways that [`try_cast_slice`] can fail.
+/// * If the output slice length isn't `expected_len`.
+#[inline]
+pub(crate) unsafe fn try_cast_slice_expect<A: Copy, B: Copy>(
+  a: &[A], expected_len: usize,
+) -> Result<&[B], PodCastError> {
+  let b: &[B] = try_cast_slice(a)?;
+  if b.len() == expected_len {
+    Ok(b)
+  } else {
+    Err(PodCastError::SizeMismatch)
+  }
+}
+
+/// As [`try_cast_slice_expect`], but `&mut`.
+#[cfg(feature = "min_const_generics")]
+#[inline]
+pub(crate) unsafe fn try_cast_slice_expect_mut<A: Copy, B: Copy>(
+  a: &mut [A], expected_len: usize,
+) -> Result<&mut [B], PodCastError> {
+  let b: &mut [B] = try_cast_slice_mut(a)?;
+  if b.len() == expected_len {
+    Ok(b)
+  } else {
+    Err(PodCastError::SizeMismatch)
+  }
+}
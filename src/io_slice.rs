@@ -0,0 +1,36 @@
+use super::*;
+use std::io::{IoSlice, IoSliceMut};
+
+/// Re-interprets `&[T]` as an [`IoSlice`] of its bytes.
+///
+/// This is for passing buffers of pod data to vectored writes such as
+/// [`Write::write_vectored`](std::io::Write::write_vectored), without casting
+/// each one to bytes by hand first.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// use std::io::Write;
+///
+/// let header = [1_u32, 2];
+/// let body = [3_u16; 4];
+/// let mut out = Vec::new();
+/// let written = out
+///   .write_vectored(&[as_io_slice(&header), as_io_slice(&body)])
+///   .unwrap();
+/// assert_eq!(written, 16);
+/// ```
+#[inline]
+pub fn as_io_slice<T: NoUninit>(s: &[T]) -> IoSlice<'_> {
+  IoSlice::new(cast_slice(s))
+}
+
+/// Re-interprets `&mut [T]` as an [`IoSliceMut`] of its bytes.
+///
+/// As [`as_io_slice`], but for vectored reads such as
+/// [`Read::read_vectored`](std::io::Read::read_vectored).
+#[inline]
+pub fn as_io_slice_mut<T: NoUninit + AnyBitPattern>(
+  s: &mut [T],
+) -> IoSliceMut<'_> {
+  IoSliceMut::new(cast_slice_mut(s))
+}
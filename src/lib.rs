@@ -89,6 +89,8 @@
 //! * `must_cast`: Provides the `must_` functions, which will compile error if
 //!   the requested cast can't be statically verified.
 //! * `const_zeroed`: Provides a const version of the `zeroed` function.
+//! * `init_as`: Provides the `init_as` function for writing a value into a
+//!   buffer of `MaybeUninit<u8>`.
 //!
 //! ## Related Crates
 //!
@@ -186,18 +188,31 @@ extern crate std;
 
 #[cfg(feature = "extern_crate_alloc")]
 extern crate alloc;
+// Used from derive generated code, for the same reason as `__core`.
+#[cfg(feature = "extern_crate_alloc")]
+#[doc(hidden)]
+pub use ::alloc::vec::Vec as __Vec;
 #[cfg(feature = "extern_crate_alloc")]
 #[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "extern_crate_alloc")))]
 pub mod allocation;
 #[cfg(feature = "extern_crate_alloc")]
 pub use allocation::*;
 
+mod aligned;
+pub use aligned::*;
+
+mod as_pod_bytes;
+pub use as_pod_bytes::*;
+
 mod anybitpattern;
 pub use anybitpattern::*;
 
 pub mod checked;
 pub use checked::CheckedBitPattern;
 
+mod checksum;
+pub use checksum::*;
+
 mod internal;
 
 mod zeroable;
@@ -207,6 +222,12 @@ pub use zeroable_in_option::*;
 
 mod pod;
 pub use pod::*;
+
+mod pod_hex;
+pub use pod_hex::*;
+
+mod pod_key;
+pub use pod_key::*;
 mod pod_in_option;
 pub use pod_in_option::*;
 
@@ -222,6 +243,45 @@ pub use no_uninit::*;
 mod contiguous;
 pub use contiguous::*;
 
+mod cursor;
+pub use cursor::*;
+
+mod dyn_slice;
+pub use dyn_slice::*;
+
+mod record_ring;
+pub use record_ring::*;
+
+mod magic;
+pub use magic::*;
+
+mod endian_agnostic;
+pub use endian_agnostic::*;
+
+mod fixed_size_pod;
+pub use fixed_size_pod::*;
+
+mod from_bytes_error;
+pub use from_bytes_error::*;
+
+#[cfg(feature = "extern_crate_std")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "extern_crate_std")))]
+mod io_slice;
+#[cfg(feature = "extern_crate_std")]
+pub use io_slice::*;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "serde")))]
+mod pod_serde;
+#[cfg(feature = "serde")]
+pub use pod_serde::*;
+
+#[cfg(feature = "extern_crate_std")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "extern_crate_std")))]
+mod tag_registry;
+#[cfg(feature = "extern_crate_std")]
+pub use tag_registry::*;
+
 mod offset_of;
 // ^ no import, the module only has a macro_rules, which are cursed and don't
 // follow normal import/export rules.
@@ -289,6 +349,183 @@ pub fn bytes_of_mut<T: NoUninit + AnyBitPattern>(t: &mut T) -> &mut [u8] {
   unsafe { internal::bytes_of_mut(t) }
 }
 
+/// Writes the bytes of each slice, in order, to a
+/// [`Hasher`](core::hash::Hasher).
+///
+/// This lets you hash a value that's spread across several buffers without
+/// first concatenating them into one allocation. Only the bytes are written,
+/// with no lengths in between, so the hash is the same as writing the
+/// concatenation of all the bytes in one call (for hashers that process their
+/// input as a stream, like the one used by `HashMap`). Empty slices and
+/// slices of zero-sized types don't write anything.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+///
+/// let mut split = DefaultHasher::new();
+/// feed_slices(&[&[1_u16, 2][..], &[], &[3]], &mut split);
+///
+/// let mut whole = DefaultHasher::new();
+/// whole.write(cast_slice(&[1_u16, 2, 3]));
+/// assert_eq!(split.finish(), whole.finish());
+/// ```
+#[inline]
+pub fn feed_slices<T: NoUninit, H: core::hash::Hasher>(
+  slices: &[&[T]], state: &mut H,
+) {
+  for s in slices {
+    let bytes: &[u8] = cast_slice(s);
+    if !bytes.is_empty() {
+      state.write(bytes);
+    }
+  }
+}
+
+maybe_const_fn! {
+  #[cfg(feature = "must_cast")]
+  /// Re-interprets `&[T]` as `&[u8]`.
+  ///
+  /// This is [`cast_slice`] to bytes, which can never fail: bytes have an
+  /// alignment of 1, and the output is always exactly `size_of::<T>()` bytes
+  /// per element. A slice of a ZST becomes an empty slice. With the
+  /// `must_cast` feature this is a `const fn`.
+  ///
+  /// ```rust
+  /// # use bytemuck::*;
+  /// let bytes: &[u8] = as_bytes(&[1_u16, 2]);
+  /// assert_eq!(bytes.len(), 4);
+  /// assert!(as_bytes(&[(); 3]).is_empty());
+  /// ```
+  #[inline]
+  #[allow(clippy::manual_slice_size_calculation)] // size_of_val isn't const
+  pub fn as_bytes<T: NoUninit>(s: &[T]) -> &[u8] {
+    let len = s.len() * size_of::<T>();
+    unsafe { core::slice::from_raw_parts(s.as_ptr() as *const u8, len) }
+  }
+}
+
+maybe_const_fn! {
+  #[cfg(feature = "must_cast_extra")]
+  /// Re-interprets `&mut [T]` as `&mut [u8]`.
+  ///
+  /// As [`as_bytes`], but `mut`. With the `must_cast_extra` feature this is a
+  /// `const fn`.
+  #[inline]
+  #[allow(clippy::manual_slice_size_calculation)] // size_of_val isn't const
+  pub fn as_bytes_mut<T: NoUninit + AnyBitPattern>(s: &mut [T]) -> &mut [u8] {
+    let len = s.len() * size_of::<T>();
+    unsafe { core::slice::from_raw_parts_mut(s.as_mut_ptr() as *mut u8, len) }
+  }
+}
+
+/// Splits `&[T]` at element `index` and re-interprets both halves as `&[u8]`.
+///
+/// This is `slice.split_at(index)` followed by casting each half to bytes, so
+/// the split always lands on an element boundary.
+///
+/// ## Panics
+///
+/// * If `index > slice.len()`, as with `split_at`.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let data = [1_u16, 2, 3];
+/// let (head, tail) = split_bytes_at_element(&data, 1);
+/// assert_eq!(head, bytes_of(&1_u16));
+/// assert_eq!(tail, cast_slice::<u16, u8>(&[2, 3]));
+/// ```
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn split_bytes_at_element<T: NoUninit>(
+  slice: &[T], index: usize,
+) -> (&[u8], &[u8]) {
+  let (head, tail) = slice.split_at(index);
+  (cast_slice(head), cast_slice(tail))
+}
+
+/// Splits `&mut [T]` at element `index` and re-interprets both halves as
+/// `&mut [u8]`.
+///
+/// As with `split_at_mut`, the two halves don't overlap, so they can be
+/// written to at the same time.
+///
+/// ## Panics
+///
+/// * If `index > slice.len()`, as with `split_at_mut`.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn split_bytes_at_element_mut<T: NoUninit + AnyBitPattern>(
+  slice: &mut [T], index: usize,
+) -> (&mut [u8], &mut [u8]) {
+  let (head, tail) = slice.split_at_mut(index);
+  (cast_slice_mut(head), cast_slice_mut(tail))
+}
+
+/// Re-interprets bytes in a "struct of arrays" layout as two slices.
+///
+/// The bytes must hold `count` of `A` followed directly by `count` of `B`,
+/// with nothing before, between, or after them. This is the reading side of
+/// writing out `cast_slice(&a)` and then `cast_slice(&b)`. There's no padding
+/// between the two regions, so each region has to already be aligned for its
+/// type. For more than two components, split the second region again.
+///
+/// ## Failure
+///
+/// * If `bytes` isn't exactly `count` of `A` and `count` of `B` this is
+///   [`PodCastError::SizeMismatch`].
+/// * If the start of either region isn't aligned for its type this is
+///   [`PodCastError::TargetAlignmentGreaterAndInputNotAligned`].
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let mut buf = [0_u32; 3];
+/// let bytes: &mut [u8] = cast_slice_mut(&mut buf);
+/// bytes[..8].copy_from_slice(cast_slice(&[1_u32, 2]));
+/// bytes[8..].copy_from_slice(cast_slice(&[3_u16, 4]));
+///
+/// let (a, b) = try_soa_split::<u32, u16>(cast_slice(&buf), 2).unwrap();
+/// assert_eq!(a, &[1, 2]);
+/// assert_eq!(b, &[3, 4]);
+/// ```
+#[inline]
+pub fn try_soa_split<A: AnyBitPattern, B: AnyBitPattern>(
+  bytes: &[u8], count: usize,
+) -> Result<(&[A], &[B]), PodCastError> {
+  let a_len = count.checked_mul(size_of::<A>());
+  let b_len = count.checked_mul(size_of::<B>());
+  let (a_len, b_len) = match (a_len, b_len) {
+    (Some(a_len), Some(b_len)) => (a_len, b_len),
+    _ => return Err(PodCastError::SizeMismatch),
+  };
+  if a_len.checked_add(b_len) != Some(bytes.len()) {
+    return Err(PodCastError::SizeMismatch);
+  }
+  let (a_bytes, b_bytes) = bytes.split_at(a_len);
+  let a: &[A] = try_cast_slice(a_bytes)?;
+  let b: &[B] = try_cast_slice(b_bytes)?;
+  // Note: casting the bytes of a zero-sized type gives an empty slice, so the
+  // lengths are set from `count` to keep `count` elements of a ZST.
+  Ok(unsafe {
+    (
+      core::slice::from_raw_parts(a.as_ptr(), count),
+      core::slice::from_raw_parts(b.as_ptr(), count),
+    )
+  })
+}
+
+/// As [`try_soa_split`], but unwraps for you.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn soa_split<A: AnyBitPattern, B: AnyBitPattern>(
+  bytes: &[u8], count: usize,
+) -> (&[A], &[B]) {
+  match try_soa_split(bytes, count) {
+    Ok(s) => s,
+    Err(e) => internal::something_went_wrong("soa_split", e),
+  }
+}
+
 /// Re-interprets `&[u8]` as `&T`.
 ///
 /// ## Panics
@@ -338,6 +575,106 @@ pub fn pod_read_unaligned<T: AnyBitPattern>(bytes: &[u8]) -> T {
   unsafe { internal::pod_read_unaligned(bytes) }
 }
 
+/// Checks if `bytes` starts at an address that's aligned for `T`.
+///
+/// This lets you pick between a zero-copy [`from_bytes`] and a copying
+/// [`pod_read_unaligned`] up front, rather than trying one and handling the
+/// error. Only the start of the slice is checked, not its length.
+#[inline]
+pub fn is_aligned_for<T: AnyBitPattern>(bytes: &[u8]) -> bool {
+  internal::is_aligned_to(bytes.as_ptr() as *const (), align_of::<T>())
+}
+
+/// Finds the first offset at or after `from` where `bytes[offset..]` starts at
+/// an address that's aligned for `T`.
+///
+/// Only the alignment is checked, not whether a whole `T` fits after the
+/// offset, so the offset can be anywhere up to and including `bytes.len()`.
+/// This gives `None` if there's no such offset, or if `from` is already past
+/// the end. A type with an alignment of 1, such as `()` or `u8`, is aligned
+/// everywhere, so for those this is just `from`.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let words = [0_u32; 2];
+/// let bytes: &[u8] = cast_slice(&words);
+/// assert_eq!(next_aligned_offset::<u32>(bytes, 1), Some(4));
+/// assert_eq!(next_aligned_offset::<u32>(&bytes[1..], 0), Some(3));
+/// assert_eq!(next_aligned_offset::<u32>(&bytes[1..6], 4), None);
+/// assert_eq!(next_aligned_offset::<u8>(bytes, 5), Some(5));
+/// ```
+#[inline]
+pub fn next_aligned_offset<T: AnyBitPattern>(
+  bytes: &[u8], from: usize,
+) -> Option<usize> {
+  if from > bytes.len() {
+    return None;
+  }
+  // Out of any `align_of::<T>()` addresses in a row, one will be aligned.
+  (from..=bytes.len()).take(align_of::<T>()).find(|&offset| {
+    internal::is_aligned_to(
+      bytes[offset..].as_ptr() as *const (),
+      align_of::<T>(),
+    )
+  })
+}
+
+/// The length of the longest prefix of `bytes` that holds a whole number of
+/// `T`.
+///
+/// Slicing to this length before [`cast_slice`] drops any ragged tail, so the
+/// only remaining way for the cast to fail is alignment. When `T` is zero-sized
+/// no bytes are needed for any number of elements, so this returns 0.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let bytes = [0_u8; 11];
+/// assert_eq!(pod_aligned_prefix_len::<u32>(&bytes), 8);
+/// assert_eq!(pod_aligned_prefix_len::<[u8; 3]>(&bytes), 9);
+/// assert_eq!(pod_aligned_prefix_len::<()>(&bytes), 0);
+/// ```
+#[inline]
+pub fn pod_aligned_prefix_len<T: AnyBitPattern>(bytes: &[u8]) -> usize {
+  if size_of::<T>() == 0 {
+    0
+  } else {
+    bytes.len() - (bytes.len() % size_of::<T>())
+  }
+}
+
+/// Reads a `T` from `bytes` at every `step` bytes, as long as a whole `T` fits.
+///
+/// The reads are unaligned and by value, and when `step < size_of::<T>()` the
+/// windows overlap, which is handy for sliding-window analysis of raw
+/// buffers. With `step == size_of::<T>()` this is just reading each
+/// consecutive `T` in turn.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let bytes = [1_u8, 2, 3, 4];
+/// let pairs: Vec<[u8; 2]> = pod_windows(&bytes, 1).collect();
+/// assert_eq!(pairs, [[1, 2], [2, 3], [3, 4]]);
+/// ```
+///
+/// ## Panics
+///
+/// * If `step` is zero.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn pod_windows<T: AnyBitPattern>(
+  bytes: &[u8], step: usize,
+) -> impl Iterator<Item = T> + '_ {
+  assert!(step != 0, "pod_windows: step must be non-zero");
+  let count = match bytes.len().checked_sub(size_of::<T>()) {
+    Some(last_start) => last_start / step + 1,
+    None => 0,
+  };
+  (0..count).map(move |i| {
+    let start = i * step;
+    pod_read_unaligned(&bytes[start..start + size_of::<T>()])
+  })
+}
+
 /// Re-interprets `&[u8]` as `&T`.
 ///
 /// ## Failure
@@ -362,6 +699,64 @@ pub fn try_from_bytes_mut<T: NoUninit + AnyBitPattern>(
   unsafe { internal::try_from_bytes_mut(s) }
 }
 
+/// Re-interprets the `size_of::<T>()` bytes of `s` starting at `offset` as
+/// `&T`.
+///
+/// This is the indexed form of [`try_from_bytes`], handy for reading a struct
+/// at a known position within a larger blob (such as an entry in a file
+/// format's offset table).
+///
+/// ## Failure
+///
+/// * If `offset + size_of::<T>()` is past the end of the slice (this gives
+///   [`PodCastError::SizeMismatch`])
+/// * If the sub-slice at `offset` isn't aligned for the new type
+#[inline]
+pub fn try_from_bytes_at<T: AnyBitPattern>(
+  s: &[u8], offset: usize,
+) -> Result<&T, PodCastError> {
+  unsafe { internal::try_from_bytes_at(s, offset) }
+}
+
+/// Re-interprets the `size_of::<T>()` bytes of `s` starting at `offset` as
+/// `&mut T`.
+///
+/// As [`try_from_bytes_at`], but `&mut`. The output borrows all of `s`, so if
+/// you need several disjoint values at once split the slice first (eg: with
+/// [`split_at_mut`](slice::split_at_mut)).
+#[inline]
+pub fn try_from_bytes_at_mut<T: NoUninit + AnyBitPattern>(
+  s: &mut [u8], offset: usize,
+) -> Result<&mut T, PodCastError> {
+  unsafe { internal::try_from_bytes_at_mut(s, offset) }
+}
+
+/// Re-interprets the `size_of::<T>()` bytes of `s` starting at `offset` as
+/// `&T`.
+///
+/// ## Panics
+///
+/// This is [`try_from_bytes_at`] but will panic on error.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn from_bytes_at<T: AnyBitPattern>(s: &[u8], offset: usize) -> &T {
+  unsafe { internal::from_bytes_at(s, offset) }
+}
+
+/// Re-interprets the `size_of::<T>()` bytes of `s` starting at `offset` as
+/// `&mut T`.
+///
+/// ## Panics
+///
+/// This is [`try_from_bytes_at_mut`] but will panic on error.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn from_bytes_at_mut<T: NoUninit + AnyBitPattern>(
+  s: &mut [u8], offset: usize,
+) -> &mut T {
+  unsafe { internal::from_bytes_at_mut(s, offset) }
+}
+
 /// Cast `A` into `B`
 ///
 /// ## Panics
@@ -445,6 +840,55 @@ pub fn pod_align_to_mut<
   unsafe { vals.align_to_mut::<U>() }
 }
 
+/// As [`pod_align_to`], but always gives the largest possible middle, and
+/// fails if that middle would be empty.
+///
+/// The standard `align_to` is allowed to put everything in the head and
+/// return an empty middle at any time, so code using it has to have a slow
+/// path even when an aligned middle would have been possible. This instead
+/// gives the shortest possible head and the longest possible middle, always,
+/// and the tail is whatever is left over.
+///
+/// ## Failure
+///
+/// * If no element of `vals` starts at an address aligned for `U`, this is
+///   [`PodCastError::TargetAlignmentGreaterAndInputNotAligned`].
+/// * If the aligned part of `vals` is too short to hold even one `U`, or if
+///   either type is zero-sized, this is [`PodCastError::SizeMismatch`].
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let words = [0_u32; 4];
+/// let bytes: &[u8] = cast_slice(&words);
+/// let (head, middle, tail) = try_pod_align_to::<u8, u32>(&bytes[1..]).unwrap();
+/// assert_eq!((head.len(), middle.len(), tail.len()), (3, 3, 0));
+///
+/// assert!(try_pod_align_to::<u8, u32>(&bytes[1..6]).is_err());
+/// ```
+#[inline]
+pub fn try_pod_align_to<T: NoUninit, U: AnyBitPattern>(
+  vals: &[T],
+) -> Result<(&[T], &[U], &[T]), PodCastError> {
+  let (head_len, middle_len) = internal::align_to_split::<T, U>(vals)?;
+  let (head, rest) = vals.split_at(head_len);
+  let (middle, tail) = rest.split_at(middle_len);
+  Ok((head, try_cast_slice(middle)?, tail))
+}
+
+/// As [`try_pod_align_to`], but for mutable slices.
+#[inline]
+pub fn try_pod_align_to_mut<
+  T: NoUninit + AnyBitPattern,
+  U: NoUninit + AnyBitPattern,
+>(
+  vals: &mut [T],
+) -> Result<(&mut [T], &mut [U], &mut [T]), PodCastError> {
+  let (head_len, middle_len) = internal::align_to_split::<T, U>(vals)?;
+  let (head, rest) = vals.split_at_mut(head_len);
+  let (middle, tail) = rest.split_at_mut(middle_len);
+  Ok((head, try_cast_slice_mut(middle)?, tail))
+}
+
 /// Try to cast `A` into `B`.
 ///
 /// Note that for this particular type of cast, alignment isn't a factor. The
@@ -452,6 +896,25 @@ pub fn pod_align_to_mut<
 /// new memory location which will have whatever the required alignment of the
 /// output type is.
 ///
+/// The input only needs to be [`NoUninit`], so that all of its bytes are
+/// defined, and the output only needs to be [`AnyBitPattern`], so that any
+/// bytes are a valid value. This means, for example, that a fieldless enum can
+/// be cast to its integer representation.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// #[derive(Clone, Copy)]
+/// #[repr(u16)]
+/// enum Op {
+///   Load = 1,
+///   Store = 2,
+/// }
+/// unsafe impl NoUninit for Op {}
+///
+/// assert_eq!(try_cast::<Op, u16>(Op::Store), Ok(2));
+/// assert_eq!(try_cast::<Op, u32>(Op::Load), Err(PodCastError::SizeMismatch));
+/// ```
+///
 /// ## Failure
 ///
 /// * If the types don't have the same size this fails.
@@ -524,6 +987,244 @@ pub fn try_cast_slice_mut<
   unsafe { internal::try_cast_slice_mut(a) }
 }
 
+/// Try to convert `&[A]` into `&[B]`, where the output must have exactly
+/// `expected_len` elements.
+///
+/// This is [`try_cast_slice`] plus a length check, which catches truncated or
+/// oversized buffers of fixed-format data in one step.
+///
+/// ## Failure
+///
+/// * All the ways that [`try_cast_slice`] can fail.
+/// * If the output slice length isn't `expected_len` this gives
+///   [`PodCastError::SizeMismatch`].
+#[inline]
+pub fn try_cast_slice_expect<A: NoUninit, B: AnyBitPattern>(
+  a: &[A], expected_len: usize,
+) -> Result<&[B], PodCastError> {
+  unsafe { internal::try_cast_slice_expect(a, expected_len) }
+}
+
+/// Cast `&[A]` into `&[B]`, where the output must have exactly `expected_len`
+/// elements.
+///
+/// ## Panics
+///
+/// This is [`try_cast_slice_expect`] but will panic on error.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn cast_slice_expect<A: NoUninit, B: AnyBitPattern>(
+  a: &[A], expected_len: usize,
+) -> &[B] {
+  match try_cast_slice_expect(a, expected_len) {
+    Ok(b) => b,
+    Err(e) => internal::something_went_wrong("cast_slice_expect", e),
+  }
+}
+
+/// Try to convert `&[A]` into `&[B; N]`.
+///
+/// This is [`try_cast_slice_expect`] with the length in the output type, for
+/// tables with a known number of entries.
+///
+/// ## Failure
+///
+/// * All the ways that [`try_cast_slice`] can fail.
+/// * If the output would have some length other than `N` this gives
+///   [`PodCastError::SizeMismatch`].
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let bytes = [0_u8; 12];
+/// let table: &[[u8; 3]; 4] = try_cast_slice_to_array(&bytes).unwrap();
+/// assert_eq!(table.len(), 4);
+/// assert_eq!(
+///   try_cast_slice_to_array::<u8, [u8; 3], 5>(&bytes),
+///   Err(PodCastError::SizeMismatch)
+/// );
+/// ```
+#[cfg(feature = "min_const_generics")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "min_const_generics")))]
+#[inline]
+pub fn try_cast_slice_to_array<
+  A: NoUninit,
+  B: AnyBitPattern,
+  const N: usize,
+>(
+  a: &[A],
+) -> Result<&[B; N], PodCastError> {
+  let b: &[B] = unsafe { internal::try_cast_slice_expect(a, N)? };
+  Ok(unsafe { &*(b.as_ptr() as *const [B; N]) })
+}
+
+/// As [`try_cast_slice_to_array`], but `&mut`.
+#[cfg(feature = "min_const_generics")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "min_const_generics")))]
+#[inline]
+pub fn try_cast_slice_to_array_mut<
+  A: NoUninit + AnyBitPattern,
+  B: NoUninit + AnyBitPattern,
+  const N: usize,
+>(
+  a: &mut [A],
+) -> Result<&mut [B; N], PodCastError> {
+  let b: &mut [B] = unsafe { internal::try_cast_slice_expect_mut(a, N)? };
+  Ok(unsafe { &mut *(b.as_mut_ptr() as *mut [B; N]) })
+}
+
+/// Convert `&[A]` into `&[B; N]`.
+///
+/// ## Panics
+///
+/// This is [`try_cast_slice_to_array`] but will panic on error.
+#[cfg(feature = "min_const_generics")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "min_const_generics")))]
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn cast_slice_to_array<A: NoUninit, B: AnyBitPattern, const N: usize>(
+  a: &[A],
+) -> &[B; N] {
+  match try_cast_slice_to_array(a) {
+    Ok(b) => b,
+    Err(e) => internal::something_went_wrong("cast_slice_to_array", e),
+  }
+}
+
+/// Convert `&mut [A]` into `&mut [B; N]`.
+///
+/// ## Panics
+///
+/// This is [`try_cast_slice_to_array_mut`] but will panic on error.
+#[cfg(feature = "min_const_generics")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "min_const_generics")))]
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn cast_slice_to_array_mut<
+  A: NoUninit + AnyBitPattern,
+  B: NoUninit + AnyBitPattern,
+  const N: usize,
+>(
+  a: &mut [A],
+) -> &mut [B; N] {
+  match try_cast_slice_to_array_mut(a) {
+    Ok(b) => b,
+    Err(e) => internal::something_went_wrong("cast_slice_to_array_mut", e),
+  }
+}
+
+/// Splits `data` into whole chunks of `LANES` elements and a shorter tail,
+/// then passes the chunks to `simd_fn` and the tail to `scalar_fn`.
+///
+/// This is the usual "vectorize the bulk, finish the tail one at a time"
+/// pattern. A `[T; LANES]` has the alignment of `T`, so every chunk can be
+/// viewed in place.
+///
+/// Each callback is only called if it has something to do. An empty `data`
+/// calls neither one, and if `data` is shorter than one chunk (or `LANES` is
+/// 0) it all goes to `scalar_fn`.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let data: Vec<f32> = (1..=10).map(|x| x as f32).collect();
+/// let mut lanes = [0.0_f32; 4];
+/// let mut tail = 0.0;
+/// process_simd::<f32, 4>(
+///   &data,
+///   |chunks| {
+///     for chunk in chunks {
+///       for (lane, x) in lanes.iter_mut().zip(chunk) {
+///         *lane += x;
+///       }
+///     }
+///   },
+///   |rest| tail = rest.iter().sum(),
+/// );
+/// assert_eq!(lanes.iter().sum::<f32>() + tail, 55.0);
+/// ```
+#[cfg(feature = "min_const_generics")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "min_const_generics")))]
+#[inline]
+pub fn process_simd<T: Pod, const LANES: usize>(
+  data: &[T], mut simd_fn: impl FnMut(&[[T; LANES]]),
+  mut scalar_fn: impl FnMut(&[T]),
+) {
+  let body_len = data.len().checked_div(LANES).unwrap_or(0) * LANES;
+  let (body, tail) = data.split_at(body_len);
+  if !body.is_empty() {
+    simd_fn(cast_slice(body));
+  }
+  if !tail.is_empty() {
+    scalar_fn(tail);
+  }
+}
+
+/// Try to convert `&Cell<[A]>` into `&Cell<[B]>` (possibly with a change in
+/// length).
+///
+/// Both types can be written through the cell, so both must be [`NoUninit`]
+/// and [`AnyBitPattern`]. This is sound because a `Cell` can't be shared
+/// between threads and never hands out references to its contents, so nothing
+/// else can be looking at the elements as `A` while they're changed as `B`.
+/// To go between `&mut [T]`, `&Cell<[T]>`, and `&[Cell<T>]`, use
+/// `Cell::from_mut` and `Cell::as_slice_of_cells`.
+///
+/// ## Failure
+///
+/// * The same as [`try_cast_slice`].
+///
+/// ```rust
+/// # use bytemuck::*;
+/// use core::cell::Cell;
+///
+/// let mut words = [0_u32; 2];
+/// let cell: &Cell<[u32]> = Cell::from_mut(&mut words[..]);
+/// let halves: &Cell<[u16]> = try_cell_cast_slice(cell).unwrap();
+/// halves.as_slice_of_cells()[1].set(0xFFFF);
+/// assert_eq!(words[0], u32::from_ne_bytes([0, 0, 0xFF, 0xFF]));
+/// ```
+#[inline]
+pub fn try_cell_cast_slice<
+  A: NoUninit + AnyBitPattern,
+  B: NoUninit + AnyBitPattern,
+>(
+  a: &core::cell::Cell<[A]>,
+) -> Result<&core::cell::Cell<[B]>, PodCastError> {
+  use core::cell::Cell;
+  // `Cell<[A]>` has the same layout as `[Cell<A>]`.
+  let cells: &[Cell<A>] =
+    unsafe { &*(a as *const Cell<[A]> as *const [Cell<A>]) };
+  // The checks are the same as for a plain slice, so run them on a short-lived
+  // `&[A]` view of the cells. Only the new length is kept from that, and the
+  // output still points at the cells themselves.
+  let elems: &[A] = unsafe {
+    core::slice::from_raw_parts(cells.as_ptr() as *const A, cells.len())
+  };
+  let new_len = unsafe { internal::try_cast_slice::<A, B>(elems)?.len() };
+  let out: &[Cell<B>] = unsafe {
+    core::slice::from_raw_parts(cells.as_ptr() as *const Cell<B>, new_len)
+  };
+  Ok(unsafe { &*(out as *const [Cell<B>] as *const Cell<[B]>) })
+}
+
+/// Convert `&Cell<[A]>` into `&Cell<[B]>` (possibly with a change in length).
+///
+/// ## Panics
+///
+/// This is [`try_cell_cast_slice`] but will panic on error.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn cell_cast_slice<
+  A: NoUninit + AnyBitPattern,
+  B: NoUninit + AnyBitPattern,
+>(
+  a: &core::cell::Cell<[A]>,
+) -> &core::cell::Cell<[B]> {
+  match try_cell_cast_slice(a) {
+    Ok(b) => b,
+    Err(e) => internal::something_went_wrong("cell_cast_slice", e),
+  }
+}
+
 /// Fill all bytes of `target` with zeroes (see [`Zeroable`]).
 ///
 /// This is similar to `*target = Zeroable::zeroed()`, but guarantees that any
@@ -568,6 +1269,138 @@ pub fn fill_zeroes<T: Zeroable>(slice: &mut [T]) {
   }
 }
 
+/// Checks if all the bytes of `t` are zero.
+///
+/// This is the "is this still in its zeroed state" check, and unlike comparing
+/// against [`Zeroable::zeroed`] it doesn't need `T: PartialEq`. It's also a
+/// check of the exact bytes, so `-0.0` isn't zeroed even though it's equal to
+/// `0.0`. Most of the bytes are checked a whole word at a time.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// assert!(is_zeroed(&[0_u32; 8]));
+/// assert!(!is_zeroed(&-0.0_f32));
+/// ```
+#[inline]
+pub fn is_zeroed<T: NoUninit>(t: &T) -> bool {
+  let bytes = bytes_of(t);
+  // Safety: any bytes are a valid `usize`.
+  let (head, words, tail) = unsafe { bytes.align_to::<usize>() };
+  head.iter().all(|&b| b == 0)
+    && words.iter().all(|&w| w == 0)
+    && tail.iter().all(|&b| b == 0)
+}
+
+/// As [`try_init_as`], but unwraps for you.
+#[cfg(feature = "init_as")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "init_as")))]
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn init_as<T: AnyBitPattern>(
+  buf: &mut [core::mem::MaybeUninit<u8>], value: T,
+) -> &mut T {
+  match try_init_as(buf, value) {
+    Ok(t) => t,
+    Err(e) => internal::something_went_wrong("init_as", e),
+  }
+}
+
+/// Try to write `value` into an uninitialized byte buffer and re-interpret the
+/// buffer as `&mut T`.
+///
+/// This lets you place a value into storage provided by the caller, such as an
+/// allocator or IO buffer, without having to zero the storage first. The whole
+/// `T` is written before the reference is made, so the reference is always to
+/// a valid `T`.
+///
+/// ## Failure
+///
+/// * If the buffer isn't aligned for `T`.
+/// * If the buffer's length isn't exactly the size of `T`.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// # use core::mem::MaybeUninit;
+/// let mut storage = [MaybeUninit::<u32>::uninit(); 2];
+/// let buf: &mut [MaybeUninit<u8>] = unsafe {
+///   core::slice::from_raw_parts_mut(storage.as_mut_ptr() as *mut _, 8)
+/// };
+/// let pair: &mut [u32; 2] = try_init_as(buf, [1, 2]).unwrap();
+/// pair[1] += 1;
+/// assert_eq!(*pair, [1, 3]);
+/// ```
+#[cfg(feature = "init_as")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "init_as")))]
+#[inline]
+pub fn try_init_as<T: AnyBitPattern>(
+  buf: &mut [core::mem::MaybeUninit<u8>], value: T,
+) -> Result<&mut T, PodCastError> {
+  if buf.len() != size_of::<T>() {
+    Err(PodCastError::SizeMismatch)
+  } else if !internal::is_aligned_to(buf.as_ptr() as *const (), align_of::<T>())
+  {
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  } else {
+    let ptr = buf.as_mut_ptr() as *mut T;
+    // Safety: the buffer is the right size and alignment for a `T`, and the
+    // `T` is written before the reference is made.
+    unsafe {
+      ptr.write(value);
+      Ok(&mut *ptr)
+    }
+  }
+}
+
+/// As [`try_cast_and_zero_source`], but unwraps for you.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn cast_and_zero_source<A: NoUninit + Zeroable, B: AnyBitPattern>(
+  a: &mut A,
+) -> B {
+  match try_cast_and_zero_source(a) {
+    Ok(b) => b,
+    Err(e) => internal::something_went_wrong("cast_and_zero_source", e),
+  }
+}
+
+/// Try to read `*a` as a `B`, then wipe the bytes of `*a` to zero.
+///
+/// This is meant for secrets such as keys, where the old copy shouldn't be
+/// left behind in memory after it's been converted. The bytes are zeroed with
+/// volatile writes followed by a compiler fence, so that the compiler can't
+/// skip the wipe because `a` is never read again.
+///
+/// Only the memory behind `a` is wiped. Copies that the compiler made in
+/// registers or on the stack along the way, and the returned `B` itself, are
+/// not.
+///
+/// ## Failure
+///
+/// * If the types don't have the same size this fails, and `a` is left
+///   unchanged.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let mut key = [0x5A_u8; 4];
+/// let word: u32 = try_cast_and_zero_source(&mut key).unwrap();
+/// assert_eq!(word, 0x5A5A_5A5A);
+/// assert_eq!(key, [0; 4]);
+/// ```
+#[inline]
+pub fn try_cast_and_zero_source<A: NoUninit + Zeroable, B: AnyBitPattern>(
+  a: &mut A,
+) -> Result<B, PodCastError> {
+  let b: B = try_cast(*a)?;
+  let ptr = a as *mut A as *mut u8;
+  for i in 0..size_of::<A>() {
+    // Safety: `ptr` is valid for `size_of::<A>()` bytes, and all zeroes is a
+    // valid `A`.
+    unsafe { core::ptr::write_volatile(ptr.add(i), 0) };
+  }
+  core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+  Ok(b)
+}
+
 /// Same as [`Zeroable::zeroed`], but as a `const fn` const.
 #[cfg(feature = "const_zeroed")]
 #[inline]
@@ -0,0 +1,109 @@
+use super::*;
+
+/// The things that can go wrong when reading data that starts with a magic
+/// value, see [`try_read_with_magic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MagicCastError {
+  /// The magic value was there, but the data after it couldn't be cast.
+  PodCastError(PodCastError),
+  /// The bytes at the front didn't match the expected magic value. This is
+  /// also given when there aren't even enough bytes for the magic value.
+  MagicMismatch,
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::fmt::Display for MagicCastError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
+#[cfg(feature = "extern_crate_std")]
+#[cfg_attr(feature = "nightly_docs", doc(cfg(feature = "extern_crate_std")))]
+impl std::error::Error for MagicCastError {}
+
+// Rust 1.81+
+#[cfg(all(feature = "impl_core_error", not(feature = "extern_crate_std")))]
+impl core::error::Error for MagicCastError {}
+
+impl From<PodCastError> for MagicCastError {
+  fn from(err: PodCastError) -> MagicCastError {
+    MagicCastError::PodCastError(err)
+  }
+}
+
+/// Checks for a magic value at the start of `bytes`, then re-interprets the
+/// rest as `&T`.
+///
+/// The magic value is read unaligned, so `bytes` only needs to be aligned
+/// enough for `T`. The `T` starts at the first offset after the magic value
+/// that's a multiple of the alignment of `T`, the same place it would be in a
+/// `#[repr(C)]` struct with the magic value as the first field. Any bytes
+/// between the two are skipped.
+///
+/// ## Failure
+///
+/// * If the start of `bytes` doesn't hold `expected` this is
+///   [`MagicCastError::MagicMismatch`].
+/// * Otherwise, casting the bytes after the magic value can fail as
+///   [`try_from_bytes`] does, giving [`MagicCastError::PodCastError`].
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let mut bytes = [0_u32; 3];
+/// cast_slice_mut::<u32, u8>(&mut bytes)[..4].copy_from_slice(b"BMCK");
+/// bytes[1] = 7;
+/// bytes[2] = 8;
+/// let body: &[u32; 2] =
+///   try_read_with_magic(cast_slice(&bytes), b"BMCK").unwrap();
+/// assert_eq!(body, &[7, 8]);
+///
+/// assert_eq!(
+///   try_read_with_magic::<_, [u32; 2]>(cast_slice(&bytes), b"ELF\0"),
+///   Err(MagicCastError::MagicMismatch)
+/// );
+/// ```
+#[inline]
+pub fn try_read_with_magic<'a, M, T>(
+  bytes: &'a [u8], expected: &M,
+) -> Result<&'a T, MagicCastError>
+where
+  M: AnyBitPattern + PartialEq,
+  T: AnyBitPattern,
+{
+  let magic: M = match bytes.get(..size_of::<M>()) {
+    Some(magic_bytes) => pod_read_unaligned(magic_bytes),
+    None => return Err(MagicCastError::MagicMismatch),
+  };
+  if magic != *expected {
+    return Err(MagicCastError::MagicMismatch);
+  }
+  let align = align_of::<T>();
+  let offset = (size_of::<M>() + align - 1) & !(align - 1);
+  match bytes.get(offset..) {
+    Some(body) => Ok(try_from_bytes(body)?),
+    None => Err(PodCastError::SizeMismatch.into()),
+  }
+}
+
+/// As [`try_read_with_magic`], but panics on failure.
+///
+/// If you want the error back as a `Result<&T, MagicCastError>`, call
+/// [`try_read_with_magic`]. As elsewhere in the crate, the name without `try_`
+/// is the one that panics.
+///
+/// ## Panics
+///
+/// * If the magic doesn't match, or the rest of the bytes can't be cast to
+///   `T`.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn read_with_magic<'a, M, T>(bytes: &'a [u8], expected: &M) -> &'a T
+where
+  M: AnyBitPattern + PartialEq,
+  T: AnyBitPattern,
+{
+  match try_read_with_magic(bytes, expected) {
+    Ok(t) => t,
+    Err(e) => internal::something_went_wrong("read_with_magic", e),
+  }
+}
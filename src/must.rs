@@ -3,7 +3,7 @@
 #![allow(clippy::let_underscore_untyped)]
 #![allow(clippy::ptr_as_ptr)]
 
-use crate::{AnyBitPattern, NoUninit};
+use crate::{Align64, AnyBitPattern, NoUninit};
 use core::mem::{align_of, size_of};
 
 struct Cast<A, B>((A, B));
@@ -43,6 +43,181 @@ pub const fn must_cast<A: NoUninit, B: AnyBitPattern>(a: A) -> B {
   unsafe { transmute!(A; B; a) }
 }
 
+/// Converts a value into an owned array of its bytes, or fails to compile.
+///
+/// This is the by-value counterpart to [`bytes_of`](crate::bytes_of): the
+/// output doesn't borrow from anything, and it can be used in const contexts.
+///
+/// ## Failure
+///
+/// * If `N` isn't exactly `size_of::<T>()` this fails to compile.
+///
+/// ## Examples
+/// ```
+/// // compiles:
+/// let bytes: [u8; 4] = bytemuck::to_bytes(1.0_f32);
+/// assert_eq!(bytes, 1.0_f32.to_ne_bytes());
+/// const WORD: [u8; 2] = bytemuck::to_bytes(0x0102_u16);
+/// assert_eq!(WORD, 0x0102_u16.to_ne_bytes());
+/// ```
+/// ```compile_fail,E0080
+/// // fails to compile (size mismatch):
+/// let bytes: [u8; 3] = bytemuck::to_bytes(12_u16);
+/// ```
+#[inline]
+pub const fn to_bytes<T: NoUninit, const N: usize>(value: T) -> [u8; N] {
+  let _ = Cast::<T, [u8; N]>::ASSERT_SIZE_EQUAL;
+  unsafe { transmute!(T; [u8; N]; value) }
+}
+
+/// Converts an owned array of bytes into a value, or fails to compile.
+///
+/// This is the reverse of [`to_bytes`].
+///
+/// ## Failure
+///
+/// * If `N` isn't exactly `size_of::<T>()` this fails to compile.
+///
+/// ## Examples
+/// ```
+/// // compiles:
+/// let x: u32 = bytemuck::from_bytes_value(7_u32.to_ne_bytes());
+/// assert_eq!(x, 7);
+/// ```
+/// ```compile_fail,E0080
+/// // fails to compile (size mismatch):
+/// let x: u32 = bytemuck::from_bytes_value([0_u8; 8]);
+/// ```
+#[inline]
+pub const fn from_bytes_value<T: AnyBitPattern, const N: usize>(
+  bytes: [u8; N],
+) -> T {
+  let _ = Cast::<[u8; N], T>::ASSERT_SIZE_EQUAL;
+  unsafe { transmute!([u8; N]; T; bytes) }
+}
+
+/// Re-interprets `&T` as a fixed-size `&[u8; N]`, or fails to compile.
+///
+/// As [`bytes_of`](crate::bytes_of), but with the length in the type, so code
+/// working on the bytes doesn't need any bounds checks. For a ZST `T` the only
+/// `N` that compiles is 0.
+///
+/// ## Failure
+///
+/// * If `N` isn't exactly `size_of::<T>()` this fails to compile.
+///
+/// ## Examples
+/// ```
+/// // compiles:
+/// let bytes: &[u8; 4] = bytemuck::bytes_of_array(&1_u32);
+/// assert_eq!(bytes, &1_u32.to_ne_bytes());
+/// ```
+/// ```compile_fail,E0080
+/// // fails to compile (size mismatch):
+/// let bytes: &[u8; 2] = bytemuck::bytes_of_array(&1_u32);
+/// ```
+#[inline]
+pub const fn bytes_of_array<T: NoUninit, const N: usize>(t: &T) -> &[u8; N] {
+  let _ = Cast::<T, [u8; N]>::ASSERT_SIZE_EQUAL;
+  unsafe { &*(t as *const T as *const [u8; N]) }
+}
+
+maybe_const_fn! {
+  #[cfg(feature = "must_cast_extra")]
+  /// Re-interprets `&mut T` as a fixed-size `&mut [u8; N]`, or fails to
+  /// compile.
+  ///
+  /// As [`bytes_of_array`], but `mut`.
+  ///
+  /// ## Examples
+  /// ```
+  /// let mut x = 0_u32;
+  /// // compiles:
+  /// let bytes: &mut [u8; 4] = bytemuck::bytes_of_mut_array(&mut x);
+  /// bytes.iter_mut().for_each(|b| *b ^= 0xFF);
+  /// assert_eq!(x, u32::MAX);
+  /// ```
+  /// ```compile_fail,E0080
+  /// # let mut x = 0_u32;
+  /// // fails to compile (size mismatch):
+  /// let bytes: &mut [u8; 8] = bytemuck::bytes_of_mut_array(&mut x);
+  /// ```
+  #[inline]
+  pub fn bytes_of_mut_array<T: NoUninit + AnyBitPattern, const N: usize>(
+    t: &mut T,
+  ) -> &mut [u8; N] {
+    let _ = Cast::<T, [u8; N]>::ASSERT_SIZE_EQUAL;
+    unsafe { &mut *(t as *mut T as *mut [u8; N]) }
+  }
+}
+
+/// Re-interprets `&T` as a fixed-size `&[E; N]` of smaller elements, or fails
+/// to compile.
+///
+/// This is [`bytes_of_array`] generalized to any element type, for taking a
+/// wide value apart into a statically known number of lanes.
+///
+/// ## Failure
+///
+/// * If `N` elements of `E` aren't exactly `size_of::<T>()` this fails to
+///   compile.
+/// * If `E` has a greater alignment requirement than `T` this fails to
+///   compile.
+///
+/// ## Examples
+/// ```
+/// // compiles:
+/// let lanes: &[u16; 8] = bytemuck::as_element_array(&0_u128);
+/// assert_eq!(lanes, &[0; 8]);
+/// ```
+/// ```compile_fail,E0080
+/// // fails to compile (size mismatch):
+/// let lanes: &[u16; 3] = bytemuck::as_element_array(&0_u64);
+/// ```
+/// ```compile_fail,E0080
+/// // fails to compile (alignment requirements increased):
+/// let lanes: &[u32; 1] = bytemuck::as_element_array(&[0_u16; 2]);
+/// ```
+#[inline]
+pub const fn as_element_array<T: NoUninit, E: AnyBitPattern, const N: usize>(
+  t: &T,
+) -> &[E; N] {
+  let _ = Cast::<T, [E; N]>::ASSERT_SIZE_EQUAL;
+  let _ = Cast::<T, [E; N]>::ASSERT_ALIGN_GREATER_THAN_EQUAL;
+  unsafe { &*(t as *const T as *const [E; N]) }
+}
+
+maybe_const_fn! {
+  #[cfg(feature = "must_cast_extra")]
+  /// As [`as_element_array`], but `mut`.
+  ///
+  /// ## Examples
+  /// ```
+  /// let mut x = 0_u64;
+  /// // compiles:
+  /// let lanes: &mut [u16; 4] = bytemuck::as_element_array_mut(&mut x);
+  /// lanes.iter_mut().for_each(|lane| *lane = u16::MAX);
+  /// assert_eq!(x, u64::MAX);
+  /// ```
+  /// ```compile_fail,E0080
+  /// # let mut x = 0_u64;
+  /// // fails to compile (size mismatch):
+  /// let lanes: &mut [u16; 8] = bytemuck::as_element_array_mut(&mut x);
+  /// ```
+  #[inline]
+  pub fn as_element_array_mut<
+    T: NoUninit + AnyBitPattern,
+    E: NoUninit + AnyBitPattern,
+    const N: usize,
+  >(
+    t: &mut T,
+  ) -> &mut [E; N] {
+    let _ = Cast::<T, [E; N]>::ASSERT_SIZE_EQUAL;
+    let _ = Cast::<T, [E; N]>::ASSERT_ALIGN_GREATER_THAN_EQUAL;
+    unsafe { &mut *(t as *mut T as *mut [E; N]) }
+  }
+}
+
 /// Convert `&A` into `&B` if infalliable, or fail to compile.
 ///
 /// ## Failure
@@ -157,6 +332,41 @@ pub const fn must_cast_slice<A: NoUninit, B: AnyBitPattern>(a: &[A]) -> &[B] {
   unsafe { core::slice::from_raw_parts(a.as_ptr() as *const B, new_len) }
 }
 
+/// Convert a slice of [`Align64`] wrappers into `&[B]`, or fail to compile.
+///
+/// The wrapper guarantees that the slice is aligned to 64, so the cast can't
+/// fail at runtime for any `B` with an alignment of 64 or less. This makes
+/// deliberately over-aligned storage usable as any SIMD type without checking
+/// a `Result`. It's the same as [`must_cast_slice`], but the name and input
+/// type say why the cast is always fine.
+///
+/// ## Failure
+///
+/// * If the alignment of `B` is more than 64, or the size of `Align64<A>` isn't
+///   a multiple of the size of `B`, this fails to compile.
+///
+/// ## Examples
+/// ```
+/// # use bytemuck::*;
+/// let buf = [Align64([1_u8; 64]); 2];
+/// // compiles:
+/// let words: &[u64] = reborrow_overaligned(&buf);
+/// assert_eq!(words.len(), 16);
+/// ```
+/// ```compile_fail,E0080
+/// # use bytemuck::*;
+/// let buf = [Align64([1_u8; 64]); 2];
+/// // fails to compile (64 bytes isn't a whole number of 48 byte chunks):
+/// let chunks: &[[u8; 48]] = reborrow_overaligned(&buf);
+/// ```
+#[inline]
+pub const fn reborrow_overaligned<A, B: AnyBitPattern>(a: &[Align64<A>]) -> &[B]
+where
+  Align64<A>: NoUninit,
+{
+  must_cast_slice(a)
+}
+
 maybe_const_fn! {
   #[cfg(feature = "must_cast_extra")]
   /// Convert `&mut [A]` into `&mut [B]` (possibly with a change in length) if
@@ -210,3 +420,62 @@ maybe_const_fn! {
     unsafe { core::slice::from_raw_parts_mut(a.as_mut_ptr() as *mut B, new_len) }
   }
 }
+
+mod sealed {
+  pub trait Sealed<B> {}
+  impl<A, B> Sealed<B> for A {}
+}
+
+/// A `where` clause bound that `Self` is the same size as `B`.
+///
+/// Generic code can write `where A: SameSize<B>` and then name
+/// [`SameSize::ASSERT_SAME_SIZE`] in its body. Any use of that code with
+/// sizes that don't match fails to compile, just like with the other `must_`
+/// functions, which turns the runtime size check of
+/// [`try_cast`](crate::try_cast) into a compile time one.
+///
+/// **Every pair of types implements this trait.** Stable Rust can't make an
+/// impl depend on the sizes of types, so the bound alone checks nothing: the
+/// check is in evaluating `ASSERT_SAME_SIZE`, which happens when the generic
+/// code is monomorphized. A bounded function that never names the constant
+/// gets no check, and unsafe code can only rely on the sizes being equal after
+/// naming it.
+///
+/// This trait is sealed, so it can't be implemented outside this crate.
+///
+/// ## Examples
+/// ```
+/// # use bytemuck::*;
+/// fn reinterpret<A, B>(a: A) -> B
+/// where
+///   A: NoUninit + SameSize<B>,
+///   B: AnyBitPattern,
+/// {
+///   let () = <A as SameSize<B>>::ASSERT_SAME_SIZE;
+///   cast(a)
+/// }
+/// // compiles:
+/// let bits: u32 = reinterpret(1.0_f32);
+/// assert_eq!(bits, 0x3F80_0000);
+/// ```
+/// ```compile_fail,E0080
+/// # use bytemuck::*;
+/// # fn reinterpret<A, B>(a: A) -> B
+/// # where
+/// #   A: NoUninit + SameSize<B>,
+/// #   B: AnyBitPattern,
+/// # {
+/// #   let () = <A as SameSize<B>>::ASSERT_SAME_SIZE;
+/// #   cast(a)
+/// # }
+/// // fails to compile (size mismatch):
+/// let bits: u64 = reinterpret(1.0_f32);
+/// ```
+pub trait SameSize<B>: sealed::Sealed<B> {
+  /// Fails to compile, when evaluated, if `Self` and `B` aren't the same size.
+  const ASSERT_SAME_SIZE: ();
+}
+
+impl<A, B> SameSize<B> for A {
+  const ASSERT_SAME_SIZE: () = Cast::<A, B>::ASSERT_SIZE_EQUAL;
+}
@@ -0,0 +1,84 @@
+use super::*;
+use core::fmt;
+
+/// Formats the bytes of a value as hex, for debugging binary data.
+///
+/// Both `Debug` and `Display` print the bytes as space-separated pairs of hex
+/// digits, in memory order. The alternate form (`{:#?}` or `{:#}`) instead
+/// prints rows of 16 bytes, each starting with its offset, like a hex editor.
+/// Nothing is allocated, so this works without `alloc`.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// assert_eq!(format!("{:?}", PodHex(&[0x01_u8, 0xAB, 0xFF])), "01 ab ff");
+///
+/// let rows = format!("{:#?}", PodHex(&[7_u8; 20]));
+/// assert_eq!(
+///   rows,
+///   "00000000: 07 07 07 07 07 07 07 07 07 07 07 07 07 07 07 07\n\
+///    00000010: 07 07 07 07"
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct PodHex<'a, T: NoUninit>(pub &'a T);
+
+/// As [`PodHex`], but for all the bytes of a slice.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// assert_eq!(format!("{}", PodHexSlice(&[0x0A0B_u16.to_be()])), "0a 0b");
+/// ```
+#[derive(Clone, Copy)]
+pub struct PodHexSlice<'a, T: NoUninit>(pub &'a [T]);
+
+#[cfg(not(target_arch = "spirv"))]
+fn fmt_hex(bytes: &[u8], f: &mut fmt::Formatter) -> fmt::Result {
+  const ROW_LEN: usize = 16;
+  if f.alternate() {
+    for (i, row) in bytes.chunks(ROW_LEN).enumerate() {
+      if i != 0 {
+        f.write_str("\n")?;
+      }
+      write!(f, "{:08x}:", i * ROW_LEN)?;
+      for byte in row {
+        write!(f, " {:02x}", byte)?;
+      }
+    }
+  } else {
+    for (i, byte) in bytes.iter().enumerate() {
+      if i != 0 {
+        f.write_str(" ")?;
+      }
+      write!(f, "{:02x}", byte)?;
+    }
+  }
+  Ok(())
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<T: NoUninit> fmt::Debug for PodHex<'_, T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt_hex(bytes_of(self.0), f)
+  }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<T: NoUninit> fmt::Display for PodHex<'_, T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt_hex(bytes_of(self.0), f)
+  }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<T: NoUninit> fmt::Debug for PodHexSlice<'_, T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt_hex(cast_slice(self.0), f)
+  }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<T: NoUninit> fmt::Display for PodHexSlice<'_, T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt_hex(cast_slice(self.0), f)
+  }
+}
@@ -0,0 +1,55 @@
+use super::*;
+use core::hash::{Hash, Hasher};
+
+/// Wraps a value so that it's hashed and compared by its bytes.
+///
+/// This lets any [`NoUninit`] type be used as a `HashMap` or `HashSet` key,
+/// even ones without their own `Hash` and `Eq` impls, such as floats, arrays of
+/// floats, or SIMD types.
+///
+/// Equality is on the bit pattern, not on the value. For floats this means
+/// that a NaN is equal to another NaN with the exact same bits (but not to a
+/// NaN with different bits), and that `0.0` and `-0.0` are not equal.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// use std::collections::HashMap;
+///
+/// let mut map = HashMap::new();
+/// map.insert(PodKey([1.0_f32, 2.0]), "a");
+/// assert_eq!(map.get(&PodKey([1.0, 2.0])), Some(&"a"));
+/// assert_eq!(map.get(&PodKey([1.0, -2.0])), None);
+///
+/// assert_ne!(PodKey(0.0_f32), PodKey(-0.0_f32));
+/// assert_eq!(PodKey(f32::NAN), PodKey(f32::NAN));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct PodKey<T>(pub T);
+
+unsafe impl<T: Zeroable> Zeroable for PodKey<T> {}
+unsafe impl<T: Pod> Pod for PodKey<T> {}
+unsafe impl<T> TransparentWrapper<T> for PodKey<T> {}
+
+impl<T: NoUninit> PartialEq for PodKey<T> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    bytes_of(&self.0) == bytes_of(&other.0)
+  }
+}
+
+impl<T: NoUninit> Eq for PodKey<T> {}
+
+impl<T: NoUninit> Hash for PodKey<T> {
+  #[inline]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    bytes_of(&self.0).hash(state)
+  }
+}
+
+impl<T> From<T> for PodKey<T> {
+  #[inline]
+  fn from(t: T) -> Self {
+    PodKey(t)
+  }
+}
@@ -0,0 +1,254 @@
+use super::*;
+use core::fmt;
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::Serializer;
+
+/// Serializes the bytes of a value, for use with `#[serde(serialize_with)]`.
+///
+/// Binary formats get the bytes as a byte array. Human readable formats get a
+/// lowercase hex string instead, since an array of numbers is a poor fit for
+/// things like JSON.
+///
+/// With serde's derive this is
+/// `#[serde(serialize_with = "bytemuck::serialize_pod")]` on a field, and it
+/// can also be called from a manual impl:
+///
+/// ```rust
+/// # use bytemuck::*;
+/// struct Header {
+///   magic: [u16; 2],
+/// }
+///
+/// impl serde::Serialize for Header {
+///   fn serialize<S: serde::Serializer>(
+///     &self, serializer: S,
+///   ) -> Result<S::Ok, S::Error> {
+///     serialize_pod(&self.magic, serializer)
+///   }
+/// }
+/// ```
+#[inline]
+pub fn serialize_pod<T: NoUninit, S: Serializer>(
+  value: &T, serializer: S,
+) -> Result<S::Ok, S::Error> {
+  serialize_bytes(bytes_of(value), serializer)
+}
+
+/// As [`serialize_pod`], but for all the bytes of a slice.
+///
+/// This works with `#[serde(serialize_with)]` on a `Vec<T>` field as well.
+#[inline]
+pub fn serialize_pod_slice<T: NoUninit, S: Serializer>(
+  slice: &[T], serializer: S,
+) -> Result<S::Ok, S::Error> {
+  serialize_bytes(cast_slice(slice), serializer)
+}
+
+/// Deserializes a value written by [`serialize_pod`], for use with
+/// `#[serde(deserialize_with)]`.
+///
+/// The bytes are copied out, so the input can have any alignment. Human
+/// readable formats must give the hex string form, in either case.
+///
+/// ## Failure
+///
+/// * If the input isn't exactly `size_of::<T>()` bytes, or isn't valid hex,
+///   this is a deserialization error.
+#[inline]
+pub fn deserialize_pod<'de, T: AnyBitPattern, D: Deserializer<'de>>(
+  deserializer: D,
+) -> Result<T, D::Error> {
+  let mut value = T::zeroed();
+  // SAFETY: every byte pattern is a valid `T`, and this covers exactly the
+  // bytes of `value`.
+  let bytes = unsafe {
+    core::slice::from_raw_parts_mut(
+      &mut value as *mut T as *mut u8,
+      size_of::<T>(),
+    )
+  };
+  deserialize_bytes(bytes, deserializer)?;
+  Ok(value)
+}
+
+/// Deserializes a `Vec` written by [`serialize_pod_slice`], for use with
+/// `#[serde(deserialize_with)]`.
+///
+/// ## Failure
+///
+/// * If the number of bytes isn't a whole number of `T`, or the input isn't
+///   valid hex, this is a deserialization error.
+#[cfg(feature = "extern_crate_alloc")]
+#[cfg_attr(
+  feature = "nightly_docs",
+  doc(cfg(all(feature = "serde", feature = "extern_crate_alloc")))
+)]
+#[inline]
+pub fn deserialize_pod_vec<'de, T: AnyBitPattern, D: Deserializer<'de>>(
+  deserializer: D,
+) -> Result<alloc::vec::Vec<T>, D::Error> {
+  let bytes = if deserializer.is_human_readable() {
+    deserializer.deserialize_str(ByteVecVisitor)?
+  } else {
+    deserializer.deserialize_bytes(ByteVecVisitor)?
+  };
+  let size = size_of::<T>();
+  if (size == 0 && !bytes.is_empty()) || (size != 0 && bytes.len() % size != 0)
+  {
+    return Err(de::Error::invalid_length(
+      bytes.len(),
+      &"a whole number of elements",
+    ));
+  }
+  let count = bytes.len().checked_div(size).unwrap_or(0);
+  let mut out: alloc::vec::Vec<T> = alloc::vec![T::zeroed(); count];
+  // SAFETY: every byte pattern is a valid `T`, and `out` holds exactly
+  // `bytes.len()` bytes.
+  unsafe {
+    core::ptr::copy_nonoverlapping(
+      bytes.as_ptr(),
+      out.as_mut_ptr() as *mut u8,
+      bytes.len(),
+    )
+  };
+  Ok(out)
+}
+
+fn serialize_bytes<S: Serializer>(
+  bytes: &[u8], serializer: S,
+) -> Result<S::Ok, S::Error> {
+  if serializer.is_human_readable() {
+    serializer.collect_str(&Hex(bytes))
+  } else {
+    serializer.serialize_bytes(bytes)
+  }
+}
+
+fn deserialize_bytes<'de, D: Deserializer<'de>>(
+  out: &mut [u8], deserializer: D,
+) -> Result<(), D::Error> {
+  if deserializer.is_human_readable() {
+    deserializer.deserialize_str(ByteFillVisitor(out))
+  } else {
+    deserializer.deserialize_bytes(ByteFillVisitor(out))
+  }
+}
+
+struct Hex<'a>(&'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    for byte in self.0 {
+      write!(f, "{:02x}", byte)?;
+    }
+    Ok(())
+  }
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+  match c {
+    b'0'..=b'9' => Some(c - b'0'),
+    b'a'..=b'f' => Some(c - b'a' + 10),
+    b'A'..=b'F' => Some(c - b'A' + 10),
+    _ => None,
+  }
+}
+
+/// Calls `f` with each byte of a hex string.
+fn for_each_hex_byte<E: de::Error>(
+  s: &str, mut f: impl FnMut(usize, u8) -> Result<(), E>,
+) -> Result<(), E> {
+  if s.len() % 2 != 0 {
+    return Err(E::invalid_value(
+      de::Unexpected::Str(s),
+      &"an even number of hex digits",
+    ));
+  }
+  for (i, pair) in s.as_bytes().chunks(2).enumerate() {
+    match (hex_digit(pair[0]), hex_digit(pair[1])) {
+      (Some(hi), Some(lo)) => f(i, hi << 4 | lo)?,
+      _ => return Err(E::invalid_value(de::Unexpected::Str(s), &"hex digits")),
+    }
+  }
+  Ok(())
+}
+
+/// Fills a buffer with exactly as many bytes as it holds.
+struct ByteFillVisitor<'a>(&'a mut [u8]);
+
+impl<'de> Visitor<'de> for ByteFillVisitor<'_> {
+  type Value = ();
+
+  fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{} bytes", self.0.len())
+  }
+
+  fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<(), E> {
+    if v.len() != self.0.len() {
+      return Err(E::invalid_length(v.len(), &self));
+    }
+    self.0.copy_from_slice(v);
+    Ok(())
+  }
+
+  fn visit_str<E: de::Error>(self, v: &str) -> Result<(), E> {
+    if v.len() != self.0.len() * 2 {
+      return Err(E::invalid_length(v.len() / 2, &self));
+    }
+    let out = self.0;
+    for_each_hex_byte(v, |i, byte| {
+      out[i] = byte;
+      Ok(())
+    })
+  }
+
+  fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+    let len = self.0.len();
+    for i in 0..len {
+      match seq.next_element::<u8>()? {
+        Some(byte) => self.0[i] = byte,
+        None => return Err(de::Error::invalid_length(i, &self)),
+      }
+    }
+    if seq.next_element::<de::IgnoredAny>()?.is_some() {
+      return Err(de::Error::invalid_length(len + 1, &self));
+    }
+    Ok(())
+  }
+}
+
+/// Collects any number of bytes.
+#[cfg(feature = "extern_crate_alloc")]
+struct ByteVecVisitor;
+
+#[cfg(feature = "extern_crate_alloc")]
+impl<'de> Visitor<'de> for ByteVecVisitor {
+  type Value = alloc::vec::Vec<u8>;
+
+  fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str("bytes")
+  }
+
+  fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+    Ok(v.to_vec())
+  }
+
+  fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+    let mut out = alloc::vec::Vec::with_capacity(v.len() / 2);
+    for_each_hex_byte(v, |_, byte| {
+      out.push(byte);
+      Ok(())
+    })?;
+    Ok(out)
+  }
+
+  fn visit_seq<A: SeqAccess<'de>>(
+    self, mut seq: A,
+  ) -> Result<Self::Value, A::Error> {
+    let mut out = alloc::vec::Vec::new();
+    while let Some(byte) = seq.next_element::<u8>()? {
+      out.push(byte);
+    }
+    Ok(out)
+  }
+}
@@ -0,0 +1,112 @@
+use super::*;
+
+/// A view of bytes as a ring of fixed-size records, where indexes wrap around
+/// past the end.
+///
+/// This is for reading circular buffers, such as a log where the writer has
+/// wrapped back to the start. Records are read by value, so the bytes can be
+/// at any alignment.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let bytes = [1_u8, 0, 2, 0, 3, 0];
+/// let ring = RecordRing::<[u8; 2]>::try_new(&bytes).unwrap();
+/// assert_eq!(ring.len(), 3);
+/// assert_eq!(ring.get(4), Some([2, 0]));
+///
+/// // reading from a write head at record 2 gives the oldest record first.
+/// let oldest_first: Vec<[u8; 2]> = ring.iter_from(2).collect();
+/// assert_eq!(oldest_first, [[3, 0], [1, 0], [2, 0]]);
+/// ```
+pub struct RecordRing<'a, T> {
+  bytes: &'a [u8],
+  _marker: PhantomData<T>,
+}
+
+impl<T> Clone for RecordRing<'_, T> {
+  #[inline]
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+impl<T> Copy for RecordRing<'_, T> {}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<T> core::fmt::Debug for RecordRing<'_, T> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    f.debug_struct("RecordRing").field("bytes", &self.bytes).finish()
+  }
+}
+
+impl<'a, T: AnyBitPattern> RecordRing<'a, T> {
+  /// Views `bytes` as a ring of `T` records.
+  ///
+  /// ## Failure
+  ///
+  /// * If `bytes` isn't a whole number of `T`, including when `T` is zero
+  ///   sized and `bytes` isn't empty, this is
+  ///   [`PodCastError::OutputSliceWouldHaveSlop`].
+  #[inline]
+  pub fn try_new(bytes: &'a [u8]) -> Result<Self, PodCastError> {
+    let size = size_of::<T>();
+    if (size == 0 && !bytes.is_empty())
+      || (size != 0 && bytes.len() % size != 0)
+    {
+      Err(PodCastError::OutputSliceWouldHaveSlop)
+    } else {
+      Ok(RecordRing { bytes, _marker: PhantomData })
+    }
+  }
+
+  /// As [`try_new`](RecordRing::try_new), but unwraps for you.
+  #[inline]
+  #[cfg_attr(feature = "track_caller", track_caller)]
+  pub fn new(bytes: &'a [u8]) -> Self {
+    match Self::try_new(bytes) {
+      Ok(ring) => ring,
+      Err(e) => internal::something_went_wrong("RecordRing::new", e),
+    }
+  }
+
+  /// The number of records.
+  #[inline]
+  pub fn len(&self) -> usize {
+    self.bytes.len().checked_div(size_of::<T>()).unwrap_or(0)
+  }
+
+  /// If there are no records.
+  #[inline]
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Reads the record at `index`, wrapping around modulo [`len`].
+  ///
+  /// This only gives `None` when there are no records at all.
+  ///
+  /// [`len`]: RecordRing::len
+  #[inline]
+  pub fn get(&self, index: usize) -> Option<T> {
+    let len = self.len();
+    if len == 0 {
+      return None;
+    }
+    let start = (index % len) * size_of::<T>();
+    Some(pod_read_unaligned(&self.bytes[start..start + size_of::<T>()]))
+  }
+
+  /// Iterates over every record once, starting at `head` and wrapping around.
+  #[inline]
+  pub fn iter_from(&self, head: usize) -> impl Iterator<Item = T> + 'a {
+    let ring = *self;
+    let len = self.len();
+    let head = head.checked_rem(len).unwrap_or(0);
+    (0..len).filter_map(move |i| ring.get(head + i))
+  }
+
+  /// All of the bytes being viewed.
+  #[inline]
+  pub fn as_bytes(&self) -> &'a [u8] {
+    self.bytes
+  }
+}
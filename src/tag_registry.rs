@@ -0,0 +1,108 @@
+use crate::checked::{self, CheckedBitPattern, CheckedCastError};
+use std::collections::HashMap;
+
+/// The things that can go wrong when validating bytes with a
+/// [`TagRegistry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TagValidationError {
+  /// No type was registered under the tag.
+  UnknownTag([u8; 4]),
+  /// The bytes weren't a valid instance of the type registered under the tag.
+  CheckedCastError(CheckedCastError),
+}
+
+impl core::fmt::Display for TagValidationError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "{:?}", self)
+  }
+}
+impl std::error::Error for TagValidationError {}
+
+impl From<CheckedCastError> for TagValidationError {
+  fn from(err: CheckedCastError) -> TagValidationError {
+    TagValidationError::CheckedCastError(err)
+  }
+}
+
+type Validator = fn(&[u8]) -> Result<(), CheckedCastError>;
+
+fn validate_as<T: CheckedBitPattern>(
+  bytes: &[u8],
+) -> Result<(), CheckedCastError> {
+  checked::try_pod_read_unaligned::<T>(bytes).map(|_| ())
+}
+
+/// Maps four byte tags (like the "fourcc" codes of RIFF or PNG chunks) to the
+/// [`CheckedBitPattern`] type stored under that tag.
+///
+/// This is for tagged binary formats where the tag read from a file selects
+/// how the payload after it should be checked. Each registered type is
+/// checked as [`checked::try_pod_read_unaligned`] would, so the payload must
+/// be exactly one value of the type and can be at any alignment.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let mut registry = TagRegistry::new();
+/// registry.register::<u32>(*b"SIZE");
+/// registry.register::<char>(*b"CHAR");
+///
+/// assert_eq!(registry.validate(*b"SIZE", &[1, 2, 3, 4]), Ok(()));
+/// assert!(registry.validate(*b"CHAR", &[0xFF; 4]).is_err());
+/// assert_eq!(
+///   registry.validate(*b"NOPE", &[]),
+///   Err(TagValidationError::UnknownTag(*b"NOPE"))
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct TagRegistry {
+  validators: HashMap<[u8; 4], Validator>,
+}
+
+impl core::fmt::Debug for TagRegistry {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    let tags: std::vec::Vec<&[u8; 4]> = self.validators.keys().collect();
+    f.debug_struct("TagRegistry").field("tags", &tags).finish()
+  }
+}
+
+impl TagRegistry {
+  /// Makes a registry with no tags.
+  #[inline]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Registers `T` as the type stored under `tag`.
+  ///
+  /// If another type was already registered under `tag` it's replaced.
+  #[inline]
+  pub fn register<T: CheckedBitPattern>(&mut self, tag: [u8; 4]) {
+    self.validators.insert(tag, validate_as::<T>);
+  }
+
+  /// Checks if any type is registered under `tag`.
+  #[inline]
+  pub fn contains(&self, tag: [u8; 4]) -> bool {
+    self.validators.contains_key(&tag)
+  }
+
+  /// Checks that `bytes` are a valid instance of the type registered under
+  /// `tag`.
+  ///
+  /// ## Failure
+  ///
+  /// * If nothing is registered under `tag` this is
+  ///   [`TagValidationError::UnknownTag`].
+  /// * If the length of `bytes` isn't the size of the type, or the bytes are
+  ///   an invalid bit pattern for the type, this is
+  ///   [`TagValidationError::CheckedCastError`].
+  #[inline]
+  pub fn validate(
+    &self, tag: [u8; 4], bytes: &[u8],
+  ) -> Result<(), TagValidationError> {
+    match self.validators.get(&tag) {
+      Some(validator) => Ok(validator(bytes)?),
+      None => Err(TagValidationError::UnknownTag(tag)),
+    }
+  }
+}
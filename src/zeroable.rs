@@ -22,6 +22,11 @@ use super::*;
 /// * `Atomic*` types require Rust 1.60.0 or later to work on certain platforms,
 ///   but is available under the `zeroable_atomics` feature flag.
 /// * `[T; N]` for arbitrary `N` requires the `min_const_generics` feature flag.
+///   Without it, only some lengths (including `0`) are covered.
+///
+/// Arrays are only `Zeroable` when their element type is, and this includes
+/// `[T; 0]`. Even though an empty array has no elements, a separate impl for
+/// it without the `T: Zeroable` bound would overlap with the impl for `[T; N]`.
 pub unsafe trait Zeroable: Sized {
   /// Calls [`zeroed`](core::mem::zeroed).
   ///
@@ -0,0 +1,59 @@
+use bytemuck::*;
+use core::mem::{align_of, size_of};
+
+#[test]
+fn test_aligned_layout() {
+  assert_eq!(align_of::<Align16<u8>>(), 16);
+  assert_eq!(align_of::<Align32<u8>>(), 32);
+  assert_eq!(align_of::<Align64<u8>>(), 64);
+  assert_eq!(size_of::<Align16<[u8; 48]>>(), 48);
+  assert_eq!(size_of::<Align64<[u8; 4]>>(), 64);
+  assert_eq!(align_of::<Align64<[u32; 32]>>(), 64);
+}
+
+#[test]
+fn test_aligned_deref() {
+  let mut a = Align32([1_u16, 2, 3]);
+  assert_eq!(a.len(), 3);
+  a[1] = 20;
+  assert_eq!(*a, [1, 20, 3]);
+  assert_eq!(Align16::from(5_u32).0, 5);
+  let z: Align64<[f32; 3]> = Zeroable::zeroed();
+  assert_eq!(z.0, [0.0; 3]);
+}
+
+#[test]
+fn test_aligned_slice_bytes() {
+  let mut buf = [Align64([0_u8; 64]); 3];
+  buf[1][0] = 7;
+  let bytes: &[u8] = cast_slice(&buf);
+  assert_eq!(bytes.len(), 3 * 64);
+  assert_eq!(bytes.as_ptr() as usize % 64, 0);
+  assert_eq!(bytes[64], 7);
+
+  let words: &[u64] = cast_slice(&buf);
+  assert_eq!(words.len(), 3 * 8);
+
+  let mut buf16 = [Align16([0_u8; 32]); 2];
+  let bytes: &mut [u8] = cast_slice_mut(&mut buf16);
+  bytes[16] = 1;
+  assert_eq!(buf16[0][16], 1);
+}
+
+#[test]
+fn test_aligned_pod_arrays() {
+  let mut lanes = [Align64([0.0_f32; 16]); 2];
+  lanes[1][15] = 1.5;
+  let bytes: &[u8] = cast_slice(&lanes);
+  assert_eq!(bytes.len(), 2 * 64);
+  assert_eq!(&bytes[124..], &1.5_f32.to_ne_bytes());
+
+  let quads: &[Align16<[u32; 4]>] = cast_slice(&lanes);
+  assert_eq!(quads.len(), 8);
+  assert_eq!(quads[7][3], 1.5_f32.to_bits());
+
+  let wide: Align32<[u128; 2]> = cast([Align16([7_u8; 16]); 2]);
+  assert_eq!(wide[1], u128::from_ne_bytes([7; 16]));
+  let z: Align16<[i16; 24]> = Zeroable::zeroed();
+  assert_eq!(bytes_of(&z), &[0; 48][..]);
+}
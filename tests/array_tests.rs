@@ -10,3 +10,104 @@ pub fn test_cast_long_array() {
   let x = [0u32; 65];
   let _: [u16; 130] = bytemuck::cast(x);
 }
+
+#[test]
+pub fn test_empty_array() {
+  use bytemuck::{Pod, Zeroable};
+  fn assert_pod<T: Pod>() {}
+  assert_pod::<[u8; 0]>();
+  assert_pod::<[u64; 0]>();
+  assert_pod::<[[u32; 4]; 0]>();
+
+  let empty: &[u8] = &[];
+  let arr: &[u8; 0] = bytemuck::from_bytes(empty);
+  assert_eq!(arr.len(), 0);
+  assert!(bytemuck::bytes_of(arr).is_empty());
+  assert!(bytemuck::try_from_bytes::<[u8; 0]>(&[1]).is_err());
+
+  // empty arrays are zero sized, but they keep the alignment of `T`.
+  assert_eq!(core::mem::size_of::<[u64; 0]>(), 0);
+  assert_eq!(core::mem::align_of::<[u64; 0]>(), core::mem::align_of::<u64>());
+  let _: [u64; 0] = bytemuck::cast(());
+  let _: [u16; 0] = Zeroable::zeroed();
+}
+
+#[cfg(feature = "min_const_generics")]
+#[test]
+pub fn test_cast_slice_to_array() {
+  use bytemuck::*;
+
+  let palette = [0_u8; 256 * 4];
+  let entries: &[[u8; 4]; 256] = cast_slice_to_array(&palette);
+  assert_eq!(entries.len(), 256);
+  assert_eq!(
+    try_cast_slice_to_array::<u8, [u8; 4], 255>(&palette),
+    Err(PodCastError::SizeMismatch)
+  );
+  assert_eq!(
+    try_cast_slice_to_array::<u8, [u8; 3], 341>(&palette),
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  );
+
+  let words = [1_u32, 2, 3, 4];
+  let pairs: &[[u32; 2]; 2] = cast_slice_to_array(&words);
+  assert_eq!(pairs, &[[1, 2], [3, 4]]);
+  let bytes: &[u8] = cast_slice(&words);
+  assert_eq!(
+    try_cast_slice_to_array::<u8, u32, 3>(&bytes[1..13]),
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  );
+  let empty: &[u8; 0] = cast_slice_to_array::<u16, u8, 0>(&[]);
+  assert!(empty.is_empty());
+
+  let mut words = [0_u16; 4];
+  {
+    let arr: &mut [u16; 4] = cast_slice_to_array_mut(&mut words);
+    arr[3] = 9;
+  }
+  assert_eq!(words, [0, 0, 0, 9]);
+  assert_eq!(
+    try_cast_slice_to_array_mut::<u16, u16, 3>(&mut words),
+    Err(PodCastError::SizeMismatch)
+  );
+}
+
+#[cfg(feature = "min_const_generics")]
+#[test]
+pub fn test_process_simd() {
+  use bytemuck::*;
+
+  fn sum(data: &[f32]) -> (f32, usize, usize) {
+    let mut lanes = [0.0_f32; 4];
+    let mut tail = 0.0;
+    let mut simd_calls = 0;
+    let mut scalar_calls = 0;
+    process_simd::<f32, 4>(
+      data,
+      |chunks| {
+        simd_calls += 1;
+        for chunk in chunks {
+          for (lane, x) in lanes.iter_mut().zip(chunk) {
+            *lane += x;
+          }
+        }
+      },
+      |rest| {
+        scalar_calls += 1;
+        assert!(rest.len() < 4);
+        tail += rest.iter().sum::<f32>();
+      },
+    );
+    (lanes.iter().sum::<f32>() + tail, simd_calls, scalar_calls)
+  }
+
+  let data: Vec<f32> = (1..=11).map(|x| x as f32).collect();
+  assert_eq!(sum(&data), (66.0, 1, 1));
+  assert_eq!(sum(&data[..8]), (36.0, 1, 0));
+  assert_eq!(sum(&data[..3]), (6.0, 0, 1));
+  assert_eq!(sum(&[]), (0.0, 0, 0));
+
+  let mut scalar: Vec<u8> = Vec::new();
+  process_simd::<u8, 0>(&[1, 2], |_| unreachable!(), |s| scalar.extend(s));
+  assert_eq!(scalar, [1, 2]);
+}
@@ -5,6 +5,16 @@ use core::mem::size_of;
 
 use bytemuck::*;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+struct Body {
+  version: u16,
+  flags: u16,
+  len: u32,
+}
+unsafe impl Zeroable for Body {}
+unsafe impl Pod for Body {}
+
 #[test]
 fn test_try_cast_slice() {
   // some align4 data
@@ -79,6 +89,29 @@ fn test_try_cast_slice_mut() {
   try_cast_slice_mut::<u8, u32>(the_bytes).unwrap();
 }
 
+#[test]
+fn test_try_cast_slice_expect() {
+  let u32s: &[u32] = &[1, 2, 3];
+  assert_eq!(
+    try_cast_slice_expect::<u32, u16>(u32s, 6).map(<[u16]>::len),
+    Ok(6)
+  );
+  assert_eq!(cast_slice_expect::<u32, u8>(u32s, 12).len(), 12);
+  assert_eq!(
+    try_cast_slice_expect::<u32, u16>(u32s, 5),
+    Err(PodCastError::SizeMismatch)
+  );
+  assert_eq!(
+    try_cast_slice_expect::<u32, u16>(u32s, 7),
+    Err(PodCastError::SizeMismatch)
+  );
+  // errors from the cast itself come first.
+  assert_eq!(
+    try_cast_slice_expect::<u32, [u8; 8]>(u32s, 1),
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  );
+}
+
 #[test]
 fn test_types() {
   let _: i32 = cast(1.0_f32);
@@ -94,6 +127,25 @@ fn test_types() {
   let _: Result<&mut [i32], PodCastError> = try_cast_slice_mut(&mut [1.0_f32]);
 }
 
+#[test]
+fn test_try_cast_no_uninit_to_any_bit_pattern() {
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  #[repr(u8)]
+  enum Level {
+    Low = 3,
+    High = 200,
+  }
+  unsafe impl NoUninit for Level {}
+
+  assert_eq!(try_cast::<Level, u8>(Level::Low), Ok(3));
+  assert_eq!(try_cast::<Level, i8>(Level::High), Ok(200_u8 as i8));
+  assert_eq!(try_cast::<Level, [u8; 1]>(Level::High), Ok([200]));
+  assert_eq!(
+    try_cast::<Level, u16>(Level::Low),
+    Err(PodCastError::SizeMismatch)
+  );
+}
+
 #[test]
 fn test_bytes_of() {
   assert_eq!(bytes_of(&0xaabbccdd_u32), &0xaabbccdd_u32.to_ne_bytes());
@@ -148,6 +200,48 @@ fn test_try_from_bytes_mut() {
   );
 }
 
+#[test]
+fn test_try_from_bytes_detailed() {
+  let mut u32s = [0xaabbccdd, 0x11223344_u32];
+  let bytes = bytemuck::cast_slice_mut::<u32, u8>(&mut u32s);
+  assert_eq!(try_from_bytes_detailed::<u32>(&bytes[..4]), Ok(&0xaabbccdd));
+  assert_eq!(
+    try_from_bytes_detailed::<u32>(&bytes[..3]),
+    Err(FromBytesError::TooShort { needed: 4, got: 3 })
+  );
+  assert_eq!(
+    try_from_bytes_detailed::<u32>(&bytes[..0]),
+    Err(FromBytesError::TooShort { needed: 4, got: 0 })
+  );
+  assert_eq!(
+    try_from_bytes_detailed::<u32>(&bytes[..5]),
+    Err(FromBytesError::TooLong { expected: 4, got: 5 })
+  );
+  assert_eq!(
+    try_from_bytes_detailed::<u32>(&bytes[1..5]),
+    Err(FromBytesError::PodCastError(
+      PodCastError::TargetAlignmentGreaterAndInputNotAligned
+    ))
+  );
+
+  *try_from_bytes_mut_detailed::<u32>(&mut bytes[4..]).unwrap() = 5;
+  assert_eq!(
+    try_from_bytes_mut_detailed::<u32>(&mut bytes[..2]),
+    Err(FromBytesError::TooShort { needed: 4, got: 2 })
+  );
+  assert_eq!(
+    try_from_bytes_mut_detailed::<u32>(bytes),
+    Err(FromBytesError::TooLong { expected: 4, got: 8 })
+  );
+  assert_eq!(u32s[1], 5);
+
+  assert_eq!(try_from_bytes_detailed::<()>(&[]), Ok(&()));
+  assert_eq!(
+    try_from_bytes_detailed::<()>(&[1]),
+    Err(FromBytesError::TooLong { expected: 0, got: 1 })
+  );
+}
+
 #[test]
 fn test_from_bytes() {
   let abcd = 0xaabbccdd_u32;
@@ -168,6 +262,464 @@ fn test_from_bytes_mut() {
   );
 }
 
+#[test]
+fn test_try_from_bytes_at() {
+  let u32s = [0xaabbccdd, 0x11223344_u32, 0x55667788];
+  let bytes = bytemuck::cast_slice::<u32, u8>(&u32s);
+  assert_eq!(try_from_bytes_at::<u32>(bytes, 0), Ok(&u32s[0]));
+  assert_eq!(try_from_bytes_at::<u32>(bytes, 8), Ok(&u32s[2]));
+  assert_eq!(try_from_bytes_at::<[u32; 2]>(bytes, 4), Ok(&[u32s[1], u32s[2]]));
+  assert_eq!(
+    try_from_bytes_at::<u32>(bytes, 9),
+    Err(PodCastError::SizeMismatch)
+  );
+  assert_eq!(
+    try_from_bytes_at::<u32>(bytes, usize::MAX),
+    Err(PodCastError::SizeMismatch)
+  );
+  assert_eq!(
+    try_from_bytes_at::<u32>(bytes, 1),
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  );
+  assert_eq!(try_from_bytes_at::<()>(bytes, 12), Ok(&()));
+}
+
+#[test]
+fn test_try_from_bytes_at_mut() {
+  let mut u32s = [0xaabbccdd, 0x11223344_u32];
+  {
+    let bytes = bytemuck::cast_slice_mut::<u32, u8>(&mut u32s);
+    *try_from_bytes_at_mut::<u32>(bytes, 4).unwrap() = 7;
+    assert_eq!(
+      try_from_bytes_at_mut::<u32>(bytes, 5),
+      Err(PodCastError::SizeMismatch)
+    );
+    assert_eq!(
+      try_from_bytes_at_mut::<u16>(bytes, 3),
+      Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+    );
+    *from_bytes_at_mut::<u32>(bytes, 0) = 5;
+  }
+  assert_eq!(u32s, [5, 7]);
+}
+
+#[test]
+fn test_is_aligned_for() {
+  let u64s = [0_u64; 2];
+  let bytes: &[u8] = cast_slice(&u64s);
+  assert!(is_aligned_for::<u64>(bytes));
+  assert!(is_aligned_for::<u32>(&bytes[4..]));
+  assert!(!is_aligned_for::<u32>(&bytes[1..]));
+  assert!(!is_aligned_for::<u16>(&bytes[1..]));
+  assert!(is_aligned_for::<u8>(&bytes[1..]));
+  assert!(is_aligned_for::<()>(&bytes[1..]));
+  assert!(is_aligned_for::<[u8; 3]>(&bytes[1..]));
+}
+
+#[test]
+fn test_next_aligned_offset() {
+  let words = [0_u64; 4];
+  let bytes: &[u8] = cast_slice(&words);
+
+  for base in 0..8 {
+    let buf = &bytes[base..];
+    for from in 0..=buf.len() {
+      let offset = next_aligned_offset::<u64>(buf, from);
+      let expected = (from..=buf.len()).find(|&o| (base + o) % 8 == 0);
+      assert_eq!(offset, expected);
+      if let Some(offset) = offset {
+        assert!(is_aligned_for::<u64>(&buf[offset..]));
+      }
+      assert_eq!(next_aligned_offset::<u8>(buf, from), Some(from));
+      assert_eq!(next_aligned_offset::<()>(buf, from), Some(from));
+    }
+    assert_eq!(next_aligned_offset::<u16>(buf, buf.len() + 1), None);
+  }
+
+  assert_eq!(next_aligned_offset::<u32>(&bytes[1..3], 0), None);
+  assert_eq!(next_aligned_offset::<[u64; 0]>(&bytes[3..], 0), Some(5));
+}
+
+#[test]
+fn test_pod_aligned_prefix_len() {
+  let u32s = [0_u32; 3];
+  let bytes: &[u8] = cast_slice(&u32s);
+  assert_eq!(pod_aligned_prefix_len::<u32>(&bytes[..11]), 8);
+  assert_eq!(pod_aligned_prefix_len::<u32>(&bytes[..12]), 12);
+  assert_eq!(pod_aligned_prefix_len::<u32>(&bytes[..3]), 0);
+  assert_eq!(pod_aligned_prefix_len::<u8>(&bytes[..7]), 7);
+  assert_eq!(pod_aligned_prefix_len::<()>(bytes), 0);
+
+  let ragged = &bytes[..10];
+  let whole: &[u32] =
+    cast_slice(&ragged[..pod_aligned_prefix_len::<u32>(ragged)]);
+  assert_eq!(whole.len(), 2);
+}
+
+#[test]
+fn test_split_bytes_at_element() {
+  let data = [0x0102_u16, 0x0304, 0x0506];
+  let (head, tail) = split_bytes_at_element(&data, 2);
+  assert_eq!(head, cast_slice::<u16, u8>(&data[..2]));
+  assert_eq!(tail, bytes_of(&data[2]));
+
+  let (head, tail) = split_bytes_at_element(&data, 0);
+  assert!(head.is_empty());
+  assert_eq!(tail.len(), 6);
+  let (head, tail) = split_bytes_at_element(&data, 3);
+  assert_eq!(head.len(), 6);
+  assert!(tail.is_empty());
+
+  let zsts = [(); 4];
+  let (head, tail) = split_bytes_at_element(&zsts, 4);
+  assert!(head.is_empty() && tail.is_empty());
+
+  let mut data = [0_u32; 3];
+  let (head, tail) = split_bytes_at_element_mut(&mut data, 1);
+  assert_eq!((head.len(), tail.len()), (4, 8));
+  head.copy_from_slice(&1_u32.to_ne_bytes());
+  tail[4..].copy_from_slice(&3_u32.to_ne_bytes());
+  assert_eq!(data, [1, 0, 3]);
+}
+
+#[test]
+#[cfg(feature = "init_as")]
+fn test_init_as() {
+  use core::mem::MaybeUninit;
+
+  #[repr(C, align(8))]
+  struct Storage([MaybeUninit<u8>; 16]);
+  let mut storage = Storage([MaybeUninit::uninit(); 16]);
+
+  let body =
+    init_as(&mut storage.0[..8], Body { version: 1, flags: 0, len: 2 });
+  body.len += 1;
+  assert_eq!(*body, Body { version: 1, flags: 0, len: 3 });
+
+  let word: &mut u64 = init_as(&mut storage.0[8..], 7);
+  assert_eq!(*word, 7);
+
+  assert_eq!(
+    try_init_as(&mut storage.0[..7], 0_u32).err(),
+    Some(PodCastError::SizeMismatch)
+  );
+  assert_eq!(
+    try_init_as(&mut storage.0[1..5], 0_u32).err(),
+    Some(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  );
+  assert_eq!(*init_as(&mut storage.0[1..5], [9_u8; 4]), [9; 4]);
+}
+
+#[test]
+fn test_is_zeroed() {
+  let mut body = Body::zeroed();
+  assert!(is_zeroed(&body));
+  // a single nonzero byte anywhere is found.
+  for i in 0..size_of::<Body>() {
+    bytes_of_mut(&mut body)[i] = 1;
+    assert!(!is_zeroed(&body));
+    bytes_of_mut(&mut body)[i] = 0;
+  }
+  assert!(is_zeroed(&body));
+  assert!(!is_zeroed(&[0_u64, 0, 0, 0, 1]));
+
+  assert!(is_zeroed(&()));
+  assert!(is_zeroed(&0_u8));
+  assert!(!is_zeroed(&[0_u8, 0, 0x80]));
+  assert!(is_zeroed(&0.0_f64));
+  assert!(!is_zeroed(&-0.0_f64));
+}
+
+#[test]
+fn test_cast_and_zero_source() {
+  let original = Body { version: 1, flags: 2, len: 3 };
+  let mut body = original;
+  let halves: [u32; 2] = cast_and_zero_source(&mut body);
+  assert_eq!(bytes_of(&halves), bytes_of(&original));
+  assert_eq!(body, Body::zeroed());
+
+  let mut word = 0x0102_0304_u32;
+  assert_eq!(
+    try_cast_and_zero_source::<u32, u16>(&mut word),
+    Err(PodCastError::SizeMismatch)
+  );
+  assert_eq!(word, 0x0102_0304);
+
+  let mut nothing = ();
+  let () = cast_and_zero_source(&mut nothing);
+}
+
+#[test]
+fn test_soa_split() {
+  let buf: [u32; 5] = [1, 2, 3, u32::from_ne_bytes([4, 0, 5, 0]), 0x0006_0006];
+  let bytes: &[u8] = cast_slice(&buf);
+
+  // three u32 then three u16, with the trailing two bytes left off.
+  let (a, b) = soa_split::<u32, [u8; 2]>(&bytes[..18], 3);
+  assert_eq!(a, &[1, 2, 3]);
+  assert_eq!(b, &[[4, 0], [5, 0], u16::to_ne_bytes(6)]);
+
+  // the length must fit exactly.
+  assert_eq!(
+    try_soa_split::<u32, u16>(bytes, 3),
+    Err(PodCastError::SizeMismatch)
+  );
+  assert_eq!(
+    try_soa_split::<u32, u16>(&bytes[..17], 3),
+    Err(PodCastError::SizeMismatch)
+  );
+  assert_eq!(
+    try_soa_split::<u8, u8>(&[], usize::MAX),
+    Err(PodCastError::SizeMismatch)
+  );
+
+  // each region must be aligned for its type.
+  assert_eq!(
+    try_soa_split::<u16, u32>(&bytes[..6], 1),
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  );
+  assert_eq!(
+    try_soa_split::<u8, u32>(&bytes[1..6], 1),
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  );
+
+  let (a, b) = soa_split::<(), u32>(&bytes[..8], 2);
+  assert_eq!((a.len(), b), (2, &[1, 2][..]));
+  let (a, b) = soa_split::<u32, u32>(&bytes[..0], 0);
+  assert!(a.is_empty() && b.is_empty());
+}
+
+#[test]
+fn test_try_pod_align_to() {
+  let words = [0_u64; 4];
+  let bytes: &[u8] = cast_slice(&words);
+
+  // every start offset, every end offset.
+  for start in 0..bytes.len() {
+    for end in start..=bytes.len() {
+      let vals = &bytes[start..end];
+      let head_len = (8 - start % 8) % 8;
+      let fits = end >= start + head_len + 8;
+      match try_pod_align_to::<u8, u64>(vals) {
+        Ok((head, middle, tail)) => {
+          assert!(fits);
+          assert_eq!(head.len(), head_len);
+          assert_eq!(middle.len(), (vals.len() - head_len) / 8);
+          assert_eq!(tail.len(), (vals.len() - head_len) % 8);
+          assert!(is_aligned_for::<u64>(cast_slice(middle)));
+        }
+        Err(e) => {
+          assert!(!fits);
+          assert!(
+            e == PodCastError::SizeMismatch
+              || e == PodCastError::TargetAlignmentGreaterAndInputNotAligned
+          );
+        }
+      }
+    }
+  }
+
+  // the middle has to end on a boundary of both types.
+  let halves: &[[u8; 2]] = cast_slice(&bytes[..30]);
+  let (head, middle, tail) =
+    try_pod_align_to::<[u8; 2], [u8; 3]>(halves).unwrap();
+  assert_eq!((head.len(), middle.len(), tail.len()), (0, 10, 0));
+  let (head, middle, tail) =
+    try_pod_align_to::<[u8; 2], [u8; 3]>(&halves[..14]).unwrap();
+  assert_eq!((head.len(), middle.len(), tail.len()), (0, 8, 2));
+
+  // no element of an odd-offset u16 slice can start at an aligned u32.
+  let halves: &[[u8; 2]] = cast_slice(&bytes[1..31]);
+  assert_eq!(
+    try_pod_align_to::<[u8; 2], u32>(halves),
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  );
+  assert_eq!(
+    try_pod_align_to::<u8, ()>(bytes),
+    Err(PodCastError::SizeMismatch)
+  );
+
+  let mut words = [0_u32; 3];
+  let bytes: &mut [u8] = cast_slice_mut(&mut words);
+  let (head, middle, tail) =
+    try_pod_align_to_mut::<u8, u32>(&mut bytes[2..]).unwrap();
+  assert_eq!((head.len(), middle.len(), tail.len()), (2, 2, 0));
+  middle[1] = u32::MAX;
+  assert_eq!(words, [0, 0, u32::MAX]);
+}
+
+#[test]
+fn test_dyn_cast_slice() {
+  let words = [0x0102_0304_u32, 0x0506_0708, 0x090A_0B0C];
+  let bytes: &[u8] = cast_slice(&words);
+
+  let elems = dyn_cast_slice(bytes, 4, 4).unwrap();
+  assert_eq!(elems.len(), 3);
+  assert_eq!(elems.elem_size(), 4);
+  for (i, word) in words.iter().enumerate() {
+    assert_eq!(elems.get(i), Some(bytes_of(word)));
+  }
+  assert_eq!(elems.get(3), None);
+  assert_eq!(elems.as_bytes(), bytes);
+
+  let elems = dyn_cast_slice(&bytes[1..7], 3, 1).unwrap();
+  assert_eq!(elems.len(), 2);
+  assert_eq!(elems.get(1), Some(&bytes[4..7]));
+
+  assert_eq!(
+    dyn_cast_slice(&bytes[1..], 1, 4),
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  );
+  assert_eq!(
+    dyn_cast_slice(bytes, 5, 1),
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  );
+  assert_eq!(
+    dyn_cast_slice(bytes, 0, 1),
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  );
+  let empty = dyn_cast_slice(&[], 0, 1).unwrap();
+  assert!(empty.is_empty());
+  assert_eq!(empty.get(0), None);
+}
+
+#[test]
+fn test_read_with_magic() {
+  let mut words = [0_u32; 4];
+  {
+    let bytes: &mut [u8] = cast_slice_mut(&mut words);
+    bytes[..2].copy_from_slice(&0xCAFE_u16.to_ne_bytes());
+    bytes[4..6].copy_from_slice(&3_u16.to_ne_bytes());
+    bytes[8..12].copy_from_slice(&99_u32.to_ne_bytes());
+  }
+  let bytes: &[u8] = cast_slice(&words);
+
+  // the body starts at the next multiple of its alignment after the magic.
+  let body: &Body = read_with_magic(&bytes[..12], &0xCAFE_u16);
+  assert_eq!(body, &Body { version: 3, flags: 0, len: 99 });
+
+  assert_eq!(
+    try_read_with_magic::<u16, Body>(&bytes[..12], &0xBEEF),
+    Err(MagicCastError::MagicMismatch)
+  );
+  assert_eq!(
+    try_read_with_magic::<u16, Body>(&bytes[..1], &0xCAFE),
+    Err(MagicCastError::MagicMismatch)
+  );
+  assert_eq!(
+    try_read_with_magic::<u16, Body>(bytes, &0xCAFE),
+    Err(MagicCastError::PodCastError(PodCastError::SizeMismatch))
+  );
+  assert_eq!(
+    try_read_with_magic::<u16, Body>(&bytes[..3], &0xCAFE),
+    Err(MagicCastError::PodCastError(PodCastError::SizeMismatch))
+  );
+  assert_eq!(
+    try_read_with_magic::<u8, u32>(&bytes[1..9], &bytes[1]),
+    Err(MagicCastError::PodCastError(
+      PodCastError::TargetAlignmentGreaterAndInputNotAligned
+    ))
+  );
+}
+
+#[test]
+fn test_as_bytes() {
+  let words = [0x0102_0304_u32, 0x0506_0708];
+  let bytes = as_bytes(&words);
+  assert_eq!(bytes, cast_slice::<u32, u8>(&words));
+  assert_eq!(bytes.as_ptr(), words.as_ptr() as *const u8);
+  assert!(as_bytes::<u64>(&[]).is_empty());
+  assert!(as_bytes(&[(); 5]).is_empty());
+
+  let mut words = [0_u16; 2];
+  as_bytes_mut(&mut words).copy_from_slice(&[0xFF; 4]);
+  assert_eq!(words, [u16::MAX; 2]);
+}
+
+#[test]
+fn test_unit_is_pod() {
+  fn assert_pod<T: Pod>() {}
+  assert_pod::<()>();
+  assert_pod::<[(); 4]>();
+  let _: () = Zeroable::zeroed();
+  let _: () = cast(());
+
+  // a unit slice has no bytes, whatever its length.
+  let units = [(); 5];
+  assert!(cast_slice::<(), u8>(&units).is_empty());
+  // casting between equal sizes keeps the length.
+  assert_eq!(cast_slice::<(), ()>(&units).len(), 5);
+  assert_eq!(cast_slice::<(), [u8; 0]>(&units).len(), 5);
+  // going to a ZST from no bytes gives no elements, and from some bytes fails.
+  assert!(cast_slice::<u8, ()>(&[]).is_empty());
+  assert_eq!(
+    try_cast_slice::<u8, ()>(&[1]),
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  );
+  assert!(bytes_of(&()).is_empty());
+  let _: &() = from_bytes(&[]);
+}
+
+#[test]
+fn test_cell_cast_slice() {
+  use core::cell::Cell;
+
+  let mut words = [0_u32; 3];
+  {
+    let cell: &Cell<[u32]> = Cell::from_mut(&mut words[..]);
+    let bytes: &Cell<[u8]> = cell_cast_slice(cell);
+    let pairs: &Cell<[[u16; 2]]> = cell_cast_slice(cell);
+    assert_eq!(bytes.as_slice_of_cells().len(), 12);
+    assert_eq!(pairs.as_slice_of_cells().len(), 3);
+
+    // writes through either view show up in the other, and in the original.
+    for b in bytes.as_slice_of_cells()[4..8].iter() {
+      b.set(0xAB);
+    }
+    assert_eq!(pairs.as_slice_of_cells()[1].get(), [0xABAB, 0xABAB]);
+    pairs.as_slice_of_cells()[2].set([1, 1]);
+    assert_eq!(bytes.as_slice_of_cells()[8].get(), 1_u16.to_ne_bytes()[0]);
+    cell.as_slice_of_cells()[0].set(7);
+    assert_eq!(pairs.as_slice_of_cells()[0].get(), cast::<u32, [u16; 2]>(7));
+  }
+  assert_eq!(words[0], 7);
+  assert_eq!(words[1], 0xABAB_ABAB);
+
+  let bytes: &mut [u8] = cast_slice_mut(&mut words);
+  assert_eq!(
+    try_cell_cast_slice::<u8, u32>(Cell::from_mut(&mut bytes[1..5])).err(),
+    Some(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  );
+  assert_eq!(
+    try_cell_cast_slice::<u8, u32>(Cell::from_mut(&mut bytes[..6])).err(),
+    Some(PodCastError::OutputSliceWouldHaveSlop)
+  );
+}
+
+#[test]
+fn test_pod_windows() {
+  let bytes: &[u8] = &[1, 2, 3, 4, 5];
+  let v: Vec<u16> = pod_windows(bytes, 1).collect();
+  assert_eq!(
+    v,
+    [
+      u16::from_ne_bytes([1, 2]),
+      u16::from_ne_bytes([2, 3]),
+      u16::from_ne_bytes([3, 4]),
+      u16::from_ne_bytes([4, 5])
+    ]
+  );
+
+  // unaligned starting point, non-overlapping step, partial tail is dropped.
+  let v: Vec<[u8; 2]> = pod_windows(&bytes[1..], 2).collect();
+  assert_eq!(v, [[2, 3], [4, 5]]);
+  let v: Vec<[u8; 2]> = pod_windows(bytes, 3).collect();
+  assert_eq!(v, [[1, 2], [4, 5]]);
+
+  assert_eq!(pod_windows::<u64>(bytes, 1).count(), 0);
+  assert_eq!(pod_windows::<u8>(&[], 1).count(), 0);
+}
+
 // like #[should_panic], but can be a part of another test, instead of requiring
 // it to be it's own test.
 macro_rules! should_panic {
@@ -190,6 +742,15 @@ fn test_panics() {
   should_panic!(from_bytes::<u32>(&[1u8, 2, 3, 4, 5]));
   should_panic!(from_bytes_mut::<u32>(&mut [1u8, 2]));
   should_panic!(from_bytes_mut::<u32>(&mut [1u8, 2, 3, 4, 5]));
+  should_panic!(from_bytes_at::<u32>(&[1u8, 2, 3, 4], 1));
+  should_panic!(cast_slice_expect::<u8, u8>(&[1u8, 2], 3));
+  should_panic!(pod_windows::<u8>(&[1u8, 2], 0));
+  should_panic!(split_bytes_at_element(&[1u16, 2], 3));
+  should_panic!(soa_split::<u8, u8>(&[1u8, 2, 3], 1));
+  should_panic!(cast_and_zero_source::<u32, u8>(&mut 1));
+  should_panic!(dyn_cast_slice(&[1u8, 2], 1, 3));
+  should_panic!(read_with_magic::<u8, u8>(&[1u8, 2], &0));
+  should_panic!(from_bytes_at_mut::<u32>(&mut [1u8, 2, 3, 4], 1));
   // use cast_slice on some u32s to get some align>=4 bytes, so we can know
   // we'll give from_bytes unaligned ones.
   let aligned_bytes = bytemuck::cast_slice::<u32, u8>(&[0, 0]);
@@ -369,3 +930,50 @@ fn box_bytes_zst() {
   // regression test for dropping zero-sized BoxBytes
   let _: BoxBytes = box_bytes_of(Box::new([0u8; 0]));
 }
+
+#[test]
+fn test_feed_slices() {
+  use std::hash::Hasher;
+
+  #[derive(Default)]
+  struct Recorder(Vec<u8>);
+  impl Hasher for Recorder {
+    fn finish(&self) -> u64 {
+      0
+    }
+    fn write(&mut self, bytes: &[u8]) {
+      self.0.extend_from_slice(bytes);
+    }
+  }
+
+  let mut r = Recorder::default();
+  feed_slices(&[&b"ab"[..], b"", b"cde"], &mut r);
+  assert_eq!(r.0, b"abcde");
+
+  let mut r = Recorder::default();
+  feed_slices(&[&[0x0102_u16][..], &[0x0304, 0x0506]], &mut r);
+  assert_eq!(r.0, bytes_of(&[0x0102_u16, 0x0304, 0x0506]));
+
+  let mut r = Recorder::default();
+  feed_slices::<(), _>(&[&[(); 4][..], &[]], &mut r);
+  feed_slices::<u32, _>(&[], &mut r);
+  assert!(r.0.is_empty());
+}
+
+#[test]
+fn test_le_bytes() {
+  let tags = [*b"RIFF", *b"WAVE"];
+  let bytes = as_le_bytes(&tags);
+  assert_eq!(bytes, b"RIFFWAVE");
+  assert_eq!(bytes.as_ptr() as usize, tags.as_ptr() as usize);
+  assert_eq!(as_le_bytes(&[-1_i8, 2]), &[0xFF, 2]);
+
+  let read: &[[u8; 4]] = from_le_bytes(bytes);
+  assert_eq!(read, tags);
+  assert_eq!(read.as_ptr() as usize, bytes.as_ptr() as usize);
+  assert_eq!(
+    try_from_le_bytes::<[u8; 3]>(bytes),
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  );
+  assert!(from_le_bytes::<u8>(&[]).is_empty());
+}
@@ -401,6 +401,14 @@ fn test_all_nonzero() {
         checked::try_cast::<$primitive, $nonzero>(1),
         Ok(<$nonzero>::new(1).unwrap())
       );
+      assert_eq!(
+        checked::try_into_nonzero::<$primitive, $nonzero>(0),
+        Err(CheckedCastError::InvalidBitPattern)
+      );
+      assert_eq!(
+        checked::try_into_nonzero::<$primitive, $nonzero>(<$primitive>::MAX),
+        Ok(<$nonzero>::new(<$primitive>::MAX).unwrap())
+      );
     };
   }
 
@@ -417,3 +425,79 @@ fn test_all_nonzero() {
   test_nonzero!(NonZeroUsize: usize);
   test_nonzero!(NonZeroIsize: isize);
 }
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_validate_slice_all() {
+  let mut chars = [u32::from('a'); 8];
+  chars[1] = 0xD800; // a surrogate
+  chars[4] = 0x11_0000; // past the last code point
+  chars[7] = u32::MAX;
+  let bytes: &[u8] = cast_slice(&chars);
+  assert_eq!(checked::validate_slice_all::<char>(bytes), Err(vec![1, 4, 7]));
+  // an unaligned start doesn't matter.
+  let mut unaligned = vec![0_u8];
+  unaligned.extend_from_slice(bytes);
+  assert_eq!(
+    checked::validate_slice_all::<char>(&unaligned[1..]),
+    Err(vec![1, 4, 7])
+  );
+  // a partial element at the end is reported too.
+  assert_eq!(
+    checked::validate_slice_all::<char>(&bytes[..18]),
+    Err(vec![1, 4])
+  );
+  assert_eq!(checked::validate_slice_all::<char>(&bytes[8..16]), Ok(()));
+
+  assert_eq!(checked::validate_slice_all::<bool>(&[]), Ok(()));
+  assert_eq!(
+    checked::validate_slice_all::<NonZeroU8>(&[0, 0]),
+    Err(vec![0, 1])
+  );
+  assert_eq!(
+    checked::validate_slice_all::<u32>(&[1, 2, 3, 4, 5]),
+    Err(vec![1])
+  );
+  assert_eq!(checked::validate_slice_all::<()>(&[]), Ok(()));
+  assert_eq!(checked::validate_slice_all::<()>(&[1]), Err(vec![0]));
+}
+
+#[test]
+fn test_try_cast_each() {
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  #[repr(u8)]
+  enum Op {
+    Push = 1,
+    Pop = 2,
+  }
+  unsafe impl checked::CheckedBitPattern for Op {
+    type Bits = u8;
+
+    fn is_valid_bit_pattern(bits: &u8) -> bool {
+      *bits == 1 || *bits == 2
+    }
+  }
+
+  let bytes = [1_u8, 3, 2, 0, 2];
+  let mut ops = checked::try_cast_each::<u8, Op>(&bytes);
+  assert_eq!(ops.next(), Some(Ok(Op::Push)));
+  assert_eq!(ops.next(), Some(Err((1, CheckedCastError::InvalidBitPattern))));
+  assert_eq!(ops.next(), Some(Ok(Op::Pop)));
+  assert_eq!(ops.next(), Some(Err((3, CheckedCastError::InvalidBitPattern))));
+  assert_eq!(ops.next(), Some(Ok(Op::Pop)));
+  assert_eq!(ops.next(), None);
+
+  let valid: Vec<Op> =
+    checked::try_cast_each(&bytes).filter_map(Result::ok).collect();
+  assert_eq!(valid, [Op::Push, Op::Pop, Op::Pop]);
+
+  let errors: Vec<_> = checked::try_cast_each::<u16, Op>(&[1, 2]).collect();
+  assert_eq!(
+    errors,
+    [
+      Err((0, CheckedCastError::PodCastError(PodCastError::SizeMismatch))),
+      Err((1, CheckedCastError::PodCastError(PodCastError::SizeMismatch)))
+    ]
+  );
+  assert_eq!(checked::try_cast_each::<u8, Op>(&[]).count(), 0);
+}
@@ -0,0 +1,40 @@
+use bytemuck::*;
+
+#[test]
+fn test_pod_checksum_known_values() {
+  assert_eq!(pod_checksum_slice::<u8>(&[]), 0);
+  assert_eq!(pod_checksum(&()), 0);
+  assert_eq!(pod_checksum(b"a"), 0xE8B7_BE43);
+  assert_eq!(pod_checksum(b"123456789"), 0xCBF4_3926);
+  assert_eq!(
+    pod_checksum_slice(&b"The quick brown fox jumps over the lazy dog"[..]),
+    0x414F_A339
+  );
+}
+
+#[test]
+fn test_pod_checksum_struct() {
+  #[derive(Clone, Copy)]
+  #[repr(C)]
+  struct Header {
+    magic: [u8; 4],
+    len: u32,
+  }
+  unsafe impl Zeroable for Header {}
+  unsafe impl Pod for Header {}
+
+  let header = Header { magic: *b"BMCK", len: 0x0102_0304 };
+  let sum = pod_checksum(&header);
+  assert_eq!(sum, pod_checksum_slice(bytes_of(&header)));
+  assert_eq!(sum, pod_checksum_slice(&[header]));
+  assert!(verify_pod_checksum(&header, sum));
+  assert!(!verify_pod_checksum(&header, sum ^ 1));
+
+  let changed = Header { len: 0x0102_0305, ..header };
+  assert!(!verify_pod_checksum(&changed, sum));
+
+  let words = [1_u32, 2, 3];
+  let sum = pod_checksum_slice(&words);
+  assert!(verify_pod_checksum_slice(&words, sum));
+  assert!(!verify_pod_checksum_slice(&words[..2], sum));
+}
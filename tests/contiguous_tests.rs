@@ -0,0 +1,41 @@
+use bytemuck::*;
+use core::num::NonZeroU8;
+
+#[repr(i8)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Signed {
+  A = -2,
+  B = -1,
+  C = 0,
+  D = 1,
+}
+unsafe impl Contiguous for Signed {
+  type Int = i8;
+  const MIN_VALUE: i8 = Signed::A as i8;
+  const MAX_VALUE: i8 = Signed::D as i8;
+}
+
+#[test]
+fn test_parse_contiguous_in_range() {
+  assert_eq!(parse_contiguous::<Signed>(-2), Ok(Signed::A));
+  assert_eq!(parse_contiguous::<Signed>(-1), Ok(Signed::B));
+  assert_eq!(parse_contiguous::<Signed>(0), Ok(Signed::C));
+  assert_eq!(parse_contiguous::<Signed>(1), Ok(Signed::D));
+  assert_eq!(parse_contiguous::<bool>(1), Ok(true));
+  assert_eq!(parse_contiguous::<NonZeroU8>(9), Ok(NonZeroU8::new(9).unwrap()));
+}
+
+#[test]
+fn test_parse_contiguous_out_of_range() {
+  let err = parse_contiguous::<Signed>(7).unwrap_err();
+  assert_eq!(err, ContiguousError { value: 7, min: -2, max: 1 });
+
+  let err = parse_contiguous::<Signed>(-3).unwrap_err();
+  assert_eq!((err.value, err.min, err.max), (-3, -2, 1));
+  assert_eq!(err.to_string(), "got -3, valid range -2..=1");
+
+  let err = parse_contiguous::<bool>(2).unwrap_err();
+  assert_eq!((err.value, err.min, err.max), (2, 0, 1));
+  let err = parse_contiguous::<NonZeroU8>(0).unwrap_err();
+  assert_eq!((err.value, err.min, err.max), (0, 1, 255));
+}
@@ -0,0 +1,37 @@
+use bytemuck::*;
+
+#[test]
+fn test_pod_cursor_mixed_values() {
+  #[derive(Clone, Copy)]
+  #[repr(C)]
+  struct Point {
+    x: i16,
+    y: i16,
+  }
+  unsafe impl Zeroable for Point {}
+  unsafe impl Pod for Point {}
+
+  let mut buf = [0_u8; 12];
+  let mut cursor = PodCursor::new(&mut buf);
+  cursor.push(0xABCD_u16).unwrap();
+  cursor.push(0x0102_0304_u32).unwrap();
+  cursor.push(Point { x: -1, y: 2 }).unwrap();
+  cursor.push(()).unwrap();
+  assert_eq!(cursor.position(), 10);
+  assert_eq!(cursor.remaining(), 2);
+
+  let written = cursor.written();
+  assert_eq!(written.len(), 10);
+  assert_eq!(&written[..2], &0xABCD_u16.to_ne_bytes());
+  // the u32 lands at an odd-ish offset, it's written unaligned.
+  assert_eq!(&written[2..6], &0x0102_0304_u32.to_ne_bytes());
+  assert_eq!(&written[6..8], &(-1_i16).to_ne_bytes());
+  assert_eq!(&written[8..10], &2_i16.to_ne_bytes());
+
+  // a value that doesn't fit is an error and writes nothing.
+  assert_eq!(cursor.push(0_u32), Err(PodCastError::SizeMismatch));
+  assert_eq!(cursor.position(), 10);
+  cursor.push([7_u8; 2]).unwrap();
+  assert_eq!(cursor.remaining(), 0);
+  assert_eq!(buf[10..], [7, 7]);
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "must_cast")]
+
+use bytemuck::*;
+
+#[test]
+fn test_to_bytes_round_trip() {
+  let bytes: [u8; 1] = to_bytes(0xAB_u8);
+  assert_eq!(bytes, [0xAB]);
+  assert_eq!(from_bytes_value::<u8, 1>(bytes), 0xAB);
+
+  let bytes: [u8; 8] = to_bytes(-3_i64);
+  assert_eq!(bytes, (-3_i64).to_ne_bytes());
+  assert_eq!(from_bytes_value::<i64, 8>(bytes), -3);
+
+  let bytes: [u8; 8] = to_bytes(2.5_f64);
+  assert_eq!(from_bytes_value::<f64, 8>(bytes), 2.5);
+
+  let bytes: [u8; 0] = to_bytes(());
+  from_bytes_value::<(), 0>(bytes);
+}
+
+#[test]
+fn test_to_bytes_struct() {
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  #[repr(C)]
+  struct Header {
+    tag: [u8; 4],
+    len: u16,
+    flags: u16,
+  }
+  unsafe impl Zeroable for Header {}
+  unsafe impl Pod for Header {}
+
+  let h = Header { tag: *b"RIFF", len: 10, flags: 3 };
+  let bytes: [u8; 8] = to_bytes(h);
+  assert_eq!(&bytes[..4], b"RIFF");
+  assert_eq!(&bytes[..], bytes_of(&h));
+  assert_eq!(from_bytes_value::<Header, 8>(bytes), h);
+}
+
+#[test]
+fn test_bytes_of_array() {
+  let x = 0x0102_0304_u32;
+  let bytes: &[u8; 4] = bytes_of_array(&x);
+  assert_eq!(bytes, &x.to_ne_bytes());
+  assert_eq!(bytes.as_ptr() as usize, &x as *const u32 as usize);
+
+  let z: &[u8; 0] = bytes_of_array(&());
+  assert!(z.is_empty());
+
+  let mut y = [0_u16; 2];
+  {
+    let bytes: &mut [u8; 4] = bytes_of_mut_array(&mut y);
+    for b in bytes.iter_mut() {
+      *b = 0xAA;
+    }
+  }
+  assert_eq!(y, [0xAAAA; 2]);
+}
+
+#[test]
+fn test_as_element_array() {
+  let x = 0x0102_0304_0506_0708_u64;
+  let bytes: &[u8; 8] = as_element_array(&x);
+  assert_eq!(bytes, &x.to_ne_bytes());
+  let halves: &[u16; 4] = as_element_array(&x);
+  assert_eq!(halves, &cast::<u64, [u16; 4]>(x));
+  assert_eq!(halves.as_ptr() as usize, &x as *const u64 as usize);
+
+  let z: &[u8; 0] = as_element_array(&());
+  assert!(z.is_empty());
+
+  let mut y = 0_u64;
+  {
+    let halves: &mut [u16; 4] = as_element_array_mut(&mut y);
+    halves[0] = 0xAAAA;
+    halves[3] = 0xAAAA;
+  }
+  assert_eq!(cast::<u64, [u16; 4]>(y), [0xAAAA, 0, 0, 0xAAAA]);
+}
+
+#[test]
+fn test_as_bytes_const() {
+  const WORDS: &[u16] = &[1, 2, 3];
+  const BYTES: &[u8] = as_bytes(WORDS);
+  assert_eq!(BYTES.len(), 6);
+  assert_eq!(BYTES, cast_slice::<u16, u8>(WORDS));
+  const EMPTY: &[u8] = as_bytes(&[(); 4]);
+  assert!(EMPTY.is_empty());
+}
+
+#[test]
+fn test_reborrow_overaligned() {
+  let mut buf = [Align64([0_u8; 64]); 2];
+  buf[1][63] = 9;
+
+  let words: &[u32] = reborrow_overaligned(&buf);
+  assert_eq!(words.len(), 32);
+  assert_eq!(words[31], u32::from_ne_bytes([0, 0, 0, 9]));
+
+  let halves: &[Align32<[u8; 32]>] = reborrow_overaligned(&buf);
+  assert_eq!(halves.len(), 4);
+  assert_eq!(halves[3][31], 9);
+
+  let empty: &[Align64<[u8; 64]>] = &[];
+  let bytes: &[u8] = reborrow_overaligned(empty);
+  assert!(bytes.is_empty());
+
+  #[cfg(target_arch = "x86_64")]
+  {
+    use core::arch::x86_64::__m256;
+    let vectors: &[__m256] = reborrow_overaligned(&buf);
+    assert_eq!(vectors.len(), 4);
+    assert_eq!(vectors.as_ptr() as usize % 64, 0);
+    assert_eq!(bytes_of(&vectors[3])[31], 9);
+  }
+}
+
+#[test]
+fn test_same_size() {
+  fn swap_halves<A, B>(a: A) -> B
+  where
+    A: NoUninit + SameSize<[u16; 2]>,
+    B: AnyBitPattern,
+    [u16; 2]: SameSize<B>,
+  {
+    let () = <A as SameSize<[u16; 2]>>::ASSERT_SAME_SIZE;
+    let () = <[u16; 2] as SameSize<B>>::ASSERT_SAME_SIZE;
+    let [lo, hi]: [u16; 2] = cast(a);
+    cast([hi, lo])
+  }
+  let swapped: [u8; 4] = swap_halves([1_u8, 2, 3, 4]);
+  assert_eq!(swapped, [3, 4, 1, 2]);
+  let swapped: u32 = swap_halves(0_i32);
+  assert_eq!(swapped, 0);
+
+  const _: () = <u64 as SameSize<[f32; 2]>>::ASSERT_SAME_SIZE;
+}
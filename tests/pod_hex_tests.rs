@@ -0,0 +1,38 @@
+use bytemuck::*;
+
+#[test]
+fn test_pod_hex() {
+  #[derive(Clone, Copy)]
+  #[repr(C)]
+  struct Header {
+    magic: [u8; 4],
+    len: u32,
+  }
+  unsafe impl Zeroable for Header {}
+  unsafe impl Pod for Header {}
+
+  let header = Header { magic: *b"BMCK", len: 0x0102_0304_u32.to_le() };
+  assert_eq!(format!("{:?}", PodHex(&header)), "42 4d 43 4b 04 03 02 01");
+  assert_eq!(format!("{}", PodHex(&header)), "42 4d 43 4b 04 03 02 01");
+  assert_eq!(
+    format!("{:#?}", PodHex(&header)),
+    "00000000: 42 4d 43 4b 04 03 02 01"
+  );
+  assert_eq!(format!("{:?}", PodHex(&())), "");
+  assert_eq!(format!("{:#?}", PodHex(&())), "");
+}
+
+#[test]
+fn test_pod_hex_slice() {
+  let bytes: Vec<u8> = (0..=33).collect();
+  assert_eq!(format!("{}", PodHexSlice(&bytes[..3])), "00 01 02");
+  assert_eq!(
+    format!("{:#}", PodHexSlice(&bytes)),
+    "00000000: 00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f\n\
+     00000010: 10 11 12 13 14 15 16 17 18 19 1a 1b 1c 1d 1e 1f\n\
+     00000020: 20 21"
+  );
+  let words = [0xAABB_u16.to_be(), 0xCCDD_u16.to_be()];
+  assert_eq!(format!("{:?}", PodHexSlice(&words)), "aa bb cc dd");
+  assert_eq!(format!("{:?}", PodHexSlice::<u32>(&[])), "");
+}
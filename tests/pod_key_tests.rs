@@ -0,0 +1,38 @@
+use bytemuck::*;
+use std::collections::{HashMap, HashSet};
+
+#[test]
+fn test_pod_key_as_map_key() {
+  let mut map: HashMap<PodKey<[f32; 4]>, usize> = HashMap::new();
+  map.insert(PodKey([0.0, 1.0, 2.0, 3.0]), 1);
+  map.insert(PodKey([1.5, -1.5, f32::INFINITY, 0.0]), 2);
+  *map.entry(PodKey([0.0, 1.0, 2.0, 3.0])).or_insert(0) += 10;
+
+  assert_eq!(map.len(), 2);
+  assert_eq!(map[&PodKey([0.0, 1.0, 2.0, 3.0])], 11);
+  assert_eq!(map[&PodKey([1.5, -1.5, f32::INFINITY, 0.0])], 2);
+  assert!(!map.contains_key(&PodKey([0.0, 1.0, 2.0, -3.0])));
+}
+
+#[test]
+fn test_pod_key_bit_equality() {
+  // -0.0 and 0.0 compare equal as floats, but not as bits.
+  assert_ne!(PodKey([0.0_f32; 4]), PodKey([-0.0, 0.0, 0.0, 0.0]));
+
+  // a NaN with the same bits is equal to itself.
+  let mut set = HashSet::new();
+  set.insert(PodKey([f32::NAN; 4]));
+  assert!(set.contains(&PodKey([f32::NAN; 4])));
+  let other_nan = f32::from_bits(f32::NAN.to_bits() ^ 1);
+  assert!(other_nan.is_nan());
+  assert!(!set.contains(&PodKey([other_nan, f32::NAN, f32::NAN, f32::NAN])));
+}
+
+#[test]
+fn test_pod_key_is_pod() {
+  let keys = [PodKey(1_u32), PodKey(2)];
+  let bytes: &[u8] = cast_slice(&keys);
+  assert_eq!(bytes.len(), 8);
+  let inner: &[u32] = PodKey::peel_slice(&keys);
+  assert_eq!(inner, [1, 2]);
+}
@@ -0,0 +1,45 @@
+use bytemuck::*;
+
+#[test]
+fn test_record_ring_wraparound() {
+  let records = [10_u32, 20, 30, 40];
+  // offset by one byte so the records aren't aligned.
+  let mut buf = vec![0_u8];
+  buf.extend_from_slice(cast_slice(&records));
+  let ring = RecordRing::<u32>::new(&buf[1..]);
+  assert_eq!(ring.len(), 4);
+  assert!(!ring.is_empty());
+  assert_eq!(ring.as_bytes().len(), 16);
+
+  assert_eq!(ring.get(0), Some(10));
+  assert_eq!(ring.get(3), Some(40));
+  assert_eq!(ring.get(4), Some(10));
+  assert_eq!(ring.get(6), Some(30));
+  assert_eq!(ring.get(usize::MAX), Some(records[usize::MAX % 4]));
+
+  let from_head: Vec<u32> = ring.iter_from(3).collect();
+  assert_eq!(from_head, [40, 10, 20, 30]);
+  let from_head: Vec<u32> = ring.iter_from(9).collect();
+  assert_eq!(from_head, [20, 30, 40, 10]);
+  let from_head: Vec<u32> = ring.iter_from(usize::MAX).collect();
+  assert_eq!(from_head.len(), 4);
+  assert_eq!(from_head[0], records[usize::MAX % 4]);
+}
+
+#[test]
+fn test_record_ring_bad_lengths() {
+  assert_eq!(
+    RecordRing::<u32>::try_new(&[0; 6]).err(),
+    Some(PodCastError::OutputSliceWouldHaveSlop)
+  );
+  assert!(RecordRing::<()>::try_new(&[0]).is_err());
+
+  let empty = RecordRing::<u16>::new(&[]);
+  assert!(empty.is_empty());
+  assert_eq!(empty.get(0), None);
+  assert_eq!(empty.iter_from(5).count(), 0);
+
+  let zsts = RecordRing::<()>::new(&[]);
+  assert_eq!(zsts.len(), 0);
+  assert_eq!(zsts.get(3), None);
+}
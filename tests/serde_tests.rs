@@ -0,0 +1,252 @@
+#![cfg(all(feature = "serde", feature = "extern_crate_alloc"))]
+
+//! These use a tiny hand-written format instead of a real serde format crate,
+//! so that testing doesn't need any more dependencies. The serializer only
+//! knows about bytes and strings, which is all the pod helpers use.
+
+use bytemuck::*;
+use serde::{de, ser};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+struct Vertex {
+  pos: [f32; 3],
+  id: u32,
+}
+unsafe impl Zeroable for Vertex {}
+unsafe impl Pod for Vertex {}
+
+#[derive(Debug, PartialEq)]
+struct TestError(String);
+
+impl fmt::Display for TestError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+impl std::error::Error for TestError {}
+impl ser::Error for TestError {
+  fn custom<T: fmt::Display>(msg: T) -> Self {
+    TestError(msg.to_string())
+  }
+}
+impl de::Error for TestError {
+  fn custom<T: fmt::Display>(msg: T) -> Self {
+    TestError(msg.to_string())
+  }
+}
+
+#[derive(Debug, PartialEq)]
+enum Output {
+  Bytes(Vec<u8>),
+  Str(String),
+}
+
+struct Sink {
+  human_readable: bool,
+}
+
+macro_rules! unsupported {
+  ($($name:ident($($arg:ty),*) -> $ret:ty;)*) => {
+    $(
+      fn $name(self, $(_: $arg),*) -> Result<$ret, TestError> {
+        Err(TestError(stringify!($name).to_string()))
+      }
+    )*
+  };
+}
+
+impl ser::Serializer for Sink {
+  type Ok = Output;
+  type Error = TestError;
+  type SerializeSeq = ser::Impossible<Output, TestError>;
+  type SerializeTuple = ser::Impossible<Output, TestError>;
+  type SerializeTupleStruct = ser::Impossible<Output, TestError>;
+  type SerializeTupleVariant = ser::Impossible<Output, TestError>;
+  type SerializeMap = ser::Impossible<Output, TestError>;
+  type SerializeStruct = ser::Impossible<Output, TestError>;
+  type SerializeStructVariant = ser::Impossible<Output, TestError>;
+
+  fn is_human_readable(&self) -> bool {
+    self.human_readable
+  }
+
+  fn serialize_bytes(self, v: &[u8]) -> Result<Output, TestError> {
+    Ok(Output::Bytes(v.to_vec()))
+  }
+
+  fn serialize_str(self, v: &str) -> Result<Output, TestError> {
+    Ok(Output::Str(v.to_string()))
+  }
+
+  fn collect_str<T: ?Sized + fmt::Display>(
+    self, value: &T,
+  ) -> Result<Output, TestError> {
+    Ok(Output::Str(value.to_string()))
+  }
+
+  fn serialize_some<T: ?Sized + ser::Serialize>(
+    self, _: &T,
+  ) -> Result<Output, TestError> {
+    Err(TestError("serialize_some".to_string()))
+  }
+
+  fn serialize_newtype_struct<T: ?Sized + ser::Serialize>(
+    self, _: &'static str, _: &T,
+  ) -> Result<Output, TestError> {
+    Err(TestError("serialize_newtype_struct".to_string()))
+  }
+
+  fn serialize_newtype_variant<T: ?Sized + ser::Serialize>(
+    self, _: &'static str, _: u32, _: &'static str, _: &T,
+  ) -> Result<Output, TestError> {
+    Err(TestError("serialize_newtype_variant".to_string()))
+  }
+
+  unsupported! {
+    serialize_bool(bool) -> Output;
+    serialize_i8(i8) -> Output;
+    serialize_i16(i16) -> Output;
+    serialize_i32(i32) -> Output;
+    serialize_i64(i64) -> Output;
+    serialize_u8(u8) -> Output;
+    serialize_u16(u16) -> Output;
+    serialize_u32(u32) -> Output;
+    serialize_u64(u64) -> Output;
+    serialize_f32(f32) -> Output;
+    serialize_f64(f64) -> Output;
+    serialize_char(char) -> Output;
+    serialize_none() -> Output;
+    serialize_unit() -> Output;
+    serialize_unit_struct(&'static str) -> Output;
+    serialize_unit_variant(&'static str, u32, &'static str) -> Output;
+    serialize_seq(Option<usize>) -> Self::SerializeSeq;
+    serialize_tuple(usize) -> Self::SerializeTuple;
+    serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct;
+    serialize_tuple_variant(&'static str, u32, &'static str, usize)
+      -> Self::SerializeTupleVariant;
+    serialize_map(Option<usize>) -> Self::SerializeMap;
+    serialize_struct(&'static str, usize) -> Self::SerializeStruct;
+    serialize_struct_variant(&'static str, u32, &'static str, usize)
+      -> Self::SerializeStructVariant;
+  }
+}
+
+/// Input for the deserializer, which it hands to whatever visitor it's given.
+#[derive(Clone, Copy)]
+enum Input<'a> {
+  Bytes(&'a [u8]),
+  Str(&'a str),
+  Seq(&'a [u8]),
+}
+
+struct Source<'a> {
+  input: Input<'a>,
+  human_readable: bool,
+}
+
+impl<'de> de::Deserializer<'de> for Source<'_> {
+  type Error = TestError;
+
+  fn is_human_readable(&self) -> bool {
+    self.human_readable
+  }
+
+  fn deserialize_any<V: de::Visitor<'de>>(
+    self, visitor: V,
+  ) -> Result<V::Value, TestError> {
+    match self.input {
+      Input::Bytes(bytes) => visitor.visit_bytes(bytes),
+      Input::Str(s) => visitor.visit_str(s),
+      Input::Seq(bytes) => {
+        visitor.visit_seq(de::value::SeqDeserializer::<_, TestError>::new(
+          bytes.iter().copied(),
+        ))
+      }
+    }
+  }
+
+  serde::forward_to_deserialize_any! {
+    bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+    bytes byte_buf option unit unit_struct newtype_struct seq tuple
+    tuple_struct map struct enum identifier ignored_any
+  }
+}
+
+fn serialize<T: NoUninit>(value: &T, human_readable: bool) -> Output {
+  serialize_pod(value, Sink { human_readable }).unwrap()
+}
+
+fn deserialize<T: AnyBitPattern>(input: Input) -> Result<T, TestError> {
+  let human_readable = matches!(input, Input::Str(_));
+  deserialize_pod(Source { input, human_readable })
+}
+
+fn deserialize_vec<T: AnyBitPattern>(
+  input: Input,
+) -> Result<Vec<T>, TestError> {
+  let human_readable = matches!(input, Input::Str(_));
+  deserialize_pod_vec(Source { input, human_readable })
+}
+
+#[test]
+fn test_serde_binary_round_trip() {
+  let v = Vertex { pos: [1.5, -2.0, 3.25], id: 7 };
+  let out = serialize(&v, false);
+  assert_eq!(out, Output::Bytes(bytes_of(&v).to_vec()));
+  let bytes = match &out {
+    Output::Bytes(bytes) => bytes,
+    _ => unreachable!(),
+  };
+  assert_eq!(deserialize::<Vertex>(Input::Bytes(bytes)), Ok(v));
+  assert_eq!(deserialize::<Vertex>(Input::Seq(bytes)), Ok(v));
+
+  // the input is copied out, so it can be at any alignment.
+  let mut buf = [0_u32; 5];
+  cast_slice_mut::<u32, u8>(&mut buf)[1..17].copy_from_slice(bytes);
+  let unaligned = &cast_slice::<u32, u8>(&buf)[1..17];
+  assert_eq!(deserialize::<Vertex>(Input::Bytes(unaligned)), Ok(v));
+
+  // the wrong number of bytes is an error rather than a truncated value.
+  assert!(deserialize::<Vertex>(Input::Bytes(&bytes[..15])).is_err());
+  assert!(deserialize::<Vertex>(Input::Seq(&bytes[..15])).is_err());
+  assert!(deserialize::<u32>(Input::Bytes(&bytes[..5])).is_err());
+  assert!(deserialize::<u32>(Input::Seq(&bytes[..5])).is_err());
+
+  let vs = [v, Vertex { pos: [4.0, 5.0, 6.0], id: 2 }];
+  let out = serialize_pod_slice(&vs, Sink { human_readable: false }).unwrap();
+  assert_eq!(out, Output::Bytes(cast_slice::<Vertex, u8>(&vs).to_vec()));
+  let all: &[u8] = cast_slice(&vs);
+  assert_eq!(deserialize_vec::<Vertex>(Input::Bytes(all)).unwrap(), vs);
+  assert_eq!(deserialize_vec::<Vertex>(Input::Seq(all)).unwrap(), vs);
+  assert!(deserialize_vec::<Vertex>(Input::Bytes(&[])).unwrap().is_empty());
+  assert!(deserialize_vec::<Vertex>(Input::Bytes(&all[..20])).is_err());
+  assert!(deserialize_vec::<()>(Input::Bytes(&all[..1])).is_err());
+}
+
+#[test]
+fn test_serde_human_readable_round_trip() {
+  let magic = 0xDEAD_BEEF_u32.to_be();
+  assert_eq!(serialize(&magic, true), Output::Str("deadbeef".to_string()));
+  assert_eq!(deserialize::<u32>(Input::Str("deadbeef")), Ok(magic));
+  assert_eq!(deserialize::<u32>(Input::Str("DEADBEEF")), Ok(magic));
+  for bad in ["deadbe", "deadbeef00", "deadbeeg", "deadbee"].iter() {
+    assert!(deserialize::<u32>(Input::Str(bad)).is_err(), "{}", bad);
+  }
+
+  let v = Vertex { pos: [0.0, 1.0, 2.0], id: 7 };
+  let hex = match serialize(&v, true) {
+    Output::Str(hex) => hex,
+    out => panic!("{:?}", out),
+  };
+  assert_eq!(hex.len(), 32);
+  assert_eq!(deserialize::<Vertex>(Input::Str(&hex)), Ok(v));
+
+  let words = [0x0102_u16.to_be(), 0x0A0B_u16.to_be()];
+  let out = serialize_pod_slice(&words, Sink { human_readable: true }).unwrap();
+  assert_eq!(out, Output::Str("01020a0b".to_string()));
+  assert_eq!(deserialize_vec::<u16>(Input::Str("01020a0b")).unwrap(), words);
+  assert!(deserialize_vec::<u16>(Input::Str("01020a")).is_err());
+  assert!(deserialize_vec::<u16>(Input::Str("01020")).is_err());
+}
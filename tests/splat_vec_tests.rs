@@ -0,0 +1,68 @@
+#![cfg(feature = "extern_crate_alloc")]
+
+//! These have their own test binary so that they can use a global allocator
+//! that records zeroed allocations.
+
+use bytemuck::*;
+use std::{
+  alloc::{GlobalAlloc, Layout, System},
+  sync::atomic::{AtomicUsize, Ordering},
+};
+
+static ZEROED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+struct RecordZeroed;
+
+unsafe impl GlobalAlloc for RecordZeroed {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    System.alloc(layout)
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    ZEROED_BYTES.fetch_add(layout.size(), Ordering::SeqCst);
+    System.alloc_zeroed(layout)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout)
+  }
+}
+
+#[global_allocator]
+static GLOBAL: RecordZeroed = RecordZeroed;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+struct Pair {
+  a: u32,
+  b: f32,
+}
+unsafe impl Zeroable for Pair {}
+unsafe impl Pod for Pair {}
+
+/// Runs `f` and gives the number of bytes it got from `alloc_zeroed`.
+fn zeroed_bytes_during<R>(f: impl FnOnce() -> R) -> (R, usize) {
+  let before = ZEROED_BYTES.load(Ordering::SeqCst);
+  let r = f();
+  (r, ZEROED_BYTES.load(Ordering::SeqCst) - before)
+}
+
+#[test]
+fn test_splat_vec_zeroed() {
+  const COUNT: usize = 4096;
+
+  // `vec!` only asks for zeroed memory for some std types, so with a struct
+  // the zeroed allocation has to come from `zeroed_vec`.
+  let (v, zeroed) = zeroed_bytes_during(|| splat_vec(Pair::zeroed(), COUNT));
+  assert_eq!(zeroed, COUNT * 8);
+  assert!(v.iter().all(|&p| p == Pair::zeroed()));
+
+  // uniform non-zero bytes and non-uniform bytes both fill a plain allocation.
+  let (v, zeroed) = zeroed_bytes_during(|| splat_vec([0xFF_u8; 8], COUNT));
+  assert_eq!(zeroed, 0);
+  assert!(v.iter().all(|&x| x == [0xFF; 8]));
+  let (v, zeroed) =
+    zeroed_bytes_during(|| splat_vec(Pair { a: 0, b: 1.0 }, COUNT));
+  assert_eq!(zeroed, 0);
+  assert!(v.iter().all(|&p| p == Pair { a: 0, b: 1.0 }));
+}
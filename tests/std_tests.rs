@@ -6,6 +6,43 @@
 use bytemuck::*;
 use core::num::NonZeroU8;
 
+#[cfg(feature = "extern_crate_alloc")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+struct Pair {
+  a: u32,
+  b: f32,
+}
+#[cfg(feature = "extern_crate_alloc")]
+unsafe impl Zeroable for Pair {}
+#[cfg(feature = "extern_crate_alloc")]
+unsafe impl Pod for Pair {}
+
+#[cfg(feature = "extern_crate_alloc")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+struct Header {
+  magic: [u8; 4],
+  len: u32,
+}
+#[cfg(feature = "extern_crate_alloc")]
+unsafe impl Zeroable for Header {}
+#[cfg(feature = "extern_crate_alloc")]
+unsafe impl Pod for Header {}
+
+#[cfg(feature = "extern_crate_std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+struct Rgb {
+  r: u8,
+  g: u8,
+  b: u8,
+}
+#[cfg(feature = "extern_crate_std")]
+unsafe impl Zeroable for Rgb {}
+#[cfg(feature = "extern_crate_std")]
+unsafe impl Pod for Rgb {}
+
 #[test]
 fn test_transparent_vtabled() {
   use core::fmt::Display;
@@ -105,3 +142,409 @@ fn test_box_bytes_of() {
   // It's ok for T to have invalid bit patterns.
   assert_eq!(&*box_bytes_of(Box::new(NonZeroU8::new(0xc5))), b"\xc5");
 }
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_splat_vec() {
+  // uniform non-zero bytes take the memset path
+  let v: Vec<u32> = splat_vec(0xABAB_ABAB_u32, 5);
+  assert_eq!(v, [0xABAB_ABAB_u32; 5]);
+
+  // non-uniform bytes fall back to a normal fill
+  let v: Vec<[u16; 2]> = splat_vec([1, 2], 3);
+  assert_eq!(v, [[1, 2]; 3]);
+
+  // ZSTs and empty output
+  assert_eq!(splat_vec((), 7).len(), 7);
+  assert!(splat_vec(5_u64, 0).is_empty());
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_split_fields() {
+  let aos =
+    [Pair { a: 1, b: 1.5 }, Pair { a: 2, b: 2.5 }, Pair { a: 3, b: 3.5 }];
+  let (a, b): (Vec<u32>, Vec<f32>) = split_fields(&aos);
+  assert_eq!(a, [1, 2, 3]);
+  assert_eq!(b, [1.5, 2.5, 3.5]);
+
+  // unaligned input is fine, since everything is read by value.
+  let bytes: Vec<u8> =
+    core::iter::once(0).chain(cast_slice(&aos).iter().copied()).collect();
+  let (a, _): (Vec<u32>, Vec<f32>) =
+    split_fields::<[u8; 8], _, _>(cast_slice(&bytes[1..]));
+  assert_eq!(a, [1, 2, 3]);
+
+  assert_eq!(
+    try_split_fields::<Pair, u32, u16>(&aos),
+    Err(PodCastError::SizeMismatch)
+  );
+  let (a, b) = split_fields::<Pair, u32, f32>(&[]);
+  assert!(a.is_empty() && b.is_empty());
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_pod_arena() {
+  let mut arena = PodArena::with_capacity(64);
+  assert_eq!(arena.capacity(), 64);
+
+  let bytes: &mut [u8] = arena.alloc_slice(3).unwrap();
+  let words: &mut [u32] = arena.alloc_slice(2).unwrap();
+  let longs: &mut [u64] = arena.alloc_slice(1).unwrap();
+  bytes.copy_from_slice(&[1, 2, 3]);
+  words[1] = 7;
+  longs[0] = 9;
+  assert_eq!(words.as_ptr() as usize % 4, 0);
+  assert_eq!(longs.as_ptr() as usize % 8, 0);
+  assert_eq!(bytes, [1, 2, 3]);
+  assert_eq!(words, [0, 7]);
+  assert_eq!(longs, [9]);
+  assert!(arena.used() >= 3 + 8 + 8);
+
+  // out of space, and a failed allocation doesn't consume anything.
+  let used = arena.used();
+  assert!(arena.alloc_slice::<u8>(64).is_none());
+  assert!(arena.alloc_slice::<u64>(usize::MAX).is_none());
+  assert_eq!(arena.used(), used);
+  assert_eq!(arena.alloc_slice::<()>(1000).unwrap().len(), 1000);
+
+  // after clearing, the space is handed out again, zeroed.
+  arena.clear();
+  assert_eq!(arena.used(), 0);
+  let all: &mut [u8] = arena.alloc_slice(64).unwrap();
+  assert!(all.iter().all(|&b| b == 0));
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_gather_bytes() {
+  let a = 0x0102_0304_u32;
+  let b = 0x0506_0708_u32;
+  let bytes = gather_bytes(&[&b, &a]);
+  assert_eq!(bytes.len(), 8);
+  assert_eq!(&bytes[..4], bytes_of(&b));
+  assert_eq!(&bytes[4..], bytes_of(&a));
+
+  assert!(gather_bytes::<u32>(&[]).is_empty());
+  assert!(gather_bytes(&[&(), &()]).is_empty());
+}
+
+#[test]
+#[cfg(all(feature = "extern_crate_alloc", feature = "min_const_generics"))]
+fn test_boxed_array_cast() {
+  let input: Box<[u32; 4]> = Box::new([1, 2, 3, 4]);
+  let ptr = &*input as *const [u32; 4] as *const u8;
+  let output: Box<[i32]> = boxed_array_cast(input);
+  assert_eq!(&*output, &[1, 2, 3, 4][..]);
+  // the allocation is reused.
+  assert_eq!(output.as_ptr() as *const u8, ptr);
+
+  let input: Box<[[u16; 2]; 3]> = Box::new([[1, 2], [3, 4], [5, 6]]);
+  let output: Box<[u16]> = boxed_array_cast(input);
+  assert_eq!(&*output, &[1, 2, 3, 4, 5, 6][..]);
+
+  // the original comes back on failure.
+  let input: Box<[u8; 3]> = Box::new([7, 8, 9]);
+  let (err, input) = try_boxed_array_cast::<_, [u8; 2], 3>(input).unwrap_err();
+  assert_eq!(err, PodCastError::OutputSliceWouldHaveSlop);
+  assert_eq!(*input, [7, 8, 9]);
+
+  let input: Box<[u16; 2]> = Box::new([1, 2]);
+  let (err, input) = try_boxed_array_cast::<_, u32, 2>(input).unwrap_err();
+  assert_eq!(err, PodCastError::AlignmentMismatch);
+  assert_eq!(*input, [1, 2]);
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_vec_to_box() {
+  let header = Header { magic: *b"BMCK", len: 12 };
+  let bytes = bytes_of(&header).to_vec();
+  let boxed: Box<Header> = vec_to_box(bytes);
+  assert_eq!(*boxed, header);
+
+  // align 1 with an exact capacity reuses the allocation.
+  let bytes = vec![1_u8, 2, 3].into_boxed_slice().into_vec();
+  let ptr = bytes.as_ptr();
+  let boxed: Box<[u8; 3]> = vec_to_box(bytes);
+  assert_eq!(*boxed, [1, 2, 3]);
+  assert_eq!(boxed.as_ptr(), ptr);
+
+  // extra capacity means copying into a new allocation.
+  let mut bytes = Vec::with_capacity(16);
+  bytes.extend_from_slice(&[4_u8, 5]);
+  let boxed: Box<[u8; 2]> = vec_to_box(bytes);
+  assert_eq!(*boxed, [4, 5]);
+
+  let boxed: Box<()> = vec_to_box(Vec::new());
+  assert_eq!(*boxed, ());
+
+  let (err, bytes) = try_vec_to_box::<Header>(vec![0; 7]).unwrap_err();
+  assert_eq!(err, PodCastError::SizeMismatch);
+  assert_eq!(bytes, [0; 7]);
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_realloc_cast_box() {
+  // matching alignment reuses the allocation.
+  let input: Box<[u16; 2]> = Box::new([1, 2]);
+  let ptr = &*input as *const [u16; 2] as usize;
+  let output: Box<[i16; 2]> = realloc_cast_box(input);
+  assert_eq!(*output, [1, 2]);
+  assert_eq!(&*output as *const [i16; 2] as usize, ptr);
+
+  // a different alignment copies into a new, correctly aligned allocation.
+  let mut bytes = [0_u8; 64];
+  bytes[0] = 5;
+  bytes[63] = 9;
+  let input: Box<[u8; 64]> = Box::new(bytes);
+  let output: Box<Align64<[u8; 64]>> = realloc_cast_box(input);
+  assert_eq!(&*output as *const _ as usize % 64, 0);
+  assert_eq!(output.0, bytes);
+
+  // and back down to a lower alignment.
+  let output: Box<[u32; 16]> = realloc_cast_box(output);
+  assert_eq!(bytes_of(&*output), &bytes[..]);
+
+  let (err, input) =
+    try_realloc_cast_box::<[u8; 3], u32>(Box::new([1, 2, 3])).unwrap_err();
+  assert_eq!(err, PodCastError::SizeMismatch);
+  assert_eq!(*input, [1, 2, 3]);
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_cast_slice_map() {
+  // read little endian words out of a byte buffer at any alignment.
+  let bytes = [0xFF_u8, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00];
+  let words: &[[u8; 4]] = cast_slice(&bytes[1..]);
+  let native: Vec<u32> = cast_slice_map(words, u32::from_le_bytes);
+  assert_eq!(native, [1, 2]);
+
+  // and swap them to big endian.
+  let swapped: Vec<[u8; 4]> = cast_slice_map(&native, u32::to_be_bytes);
+  assert_eq!(swapped, [[0, 0, 0, 1], [0, 0, 0, 2]]);
+
+  let widened = cast_slice_map(&[1_u8, 2, 3], |b| u64::from(b) << 32);
+  assert_eq!(widened, [1 << 32, 2 << 32, 3 << 32]);
+  assert!(cast_slice_map(&[0_u8; 0], u16::from).is_empty());
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_zeroed_bytes() {
+  let empty = zeroed_bytes(0);
+  assert!(empty.is_empty());
+
+  let bytes = zeroed_bytes(1 << 20);
+  assert_eq!(bytes.len(), 1 << 20);
+  assert!(bytes.iter().all(|&b| b == 0));
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_as_pod_bytes() {
+  fn byte_len<D: AsPodBytes + ?Sized>(data: &D) -> usize {
+    data.as_pod_bytes().len()
+  }
+
+  let value = 0x0102_0304_u32;
+  assert_eq!(byte_len(&value), 4);
+  assert_eq!(value.as_pod_bytes(), bytes_of(&value));
+
+  let slice: &[u16] = &[1, 2, 3];
+  assert_eq!(byte_len(slice), 6);
+  assert_eq!(slice.as_pod_bytes(), cast_slice::<u16, u8>(slice));
+
+  let vec: Vec<u64> = vec![7; 5];
+  assert_eq!(byte_len(&vec), 40);
+  let boxed: Box<[u8]> = vec![1, 2].into_boxed_slice();
+  assert_eq!(byte_len(&boxed), 2);
+  assert_eq!(byte_len(&[(); 8]), 0);
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_cast_into_vec() {
+  let mut out: Vec<u32> = Vec::with_capacity(4);
+  let ptr = out.as_ptr();
+
+  let bytes = [1_u32, 2, 3, 4];
+  // an unaligned source is fine, the bytes are copied.
+  let unaligned: &[u8] = &cast_slice::<u32, u8>(&bytes)[1..9];
+  cast_into_vec(unaligned, &mut out);
+  assert_eq!(out.len(), 2);
+  assert_eq!(cast_slice::<u32, u8>(&out), unaligned);
+
+  cast_into_vec(&[5_u16, 0, 6, 0, 7, 0, 8, 0], &mut out);
+  assert_eq!(out.len(), 4);
+  assert_eq!(
+    cast_slice::<u32, u8>(&out),
+    cast_slice::<u16, u8>(&[5, 0, 6, 0, 7, 0, 8, 0])
+  );
+  // the capacity was enough, so the allocation was reused.
+  assert_eq!(out.as_ptr(), ptr);
+
+  assert_eq!(
+    try_cast_into_vec(&[1_u8, 2, 3], &mut out),
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  );
+  assert_eq!(out.len(), 4);
+
+  cast_into_vec::<u64, u32>(&[], &mut out);
+  assert!(out.is_empty());
+
+  let mut zsts: Vec<()> = Vec::new();
+  cast_into_vec::<u8, ()>(&[], &mut zsts);
+  assert_eq!(
+    try_cast_into_vec(&[1_u8], &mut zsts),
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  );
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_length_prefixed() {
+  let pairs = [Pair { a: 1, b: 1.5 }, Pair { a: 2, b: -2.0 }];
+  let mut out = vec![0xEE];
+  write_length_prefixed(&pairs, &mut out);
+  write_length_prefixed_u64(&[7_u8, 8, 9], &mut out);
+  write_length_prefixed::<u64>(&[], &mut out);
+  assert_eq!(&out[1..5], &[2, 0, 0, 0]);
+  assert_eq!(out.len(), 1 + (4 + 16) + (8 + 3) + 4);
+
+  // the data after a 1 byte header and a 4 byte prefix is misaligned, and
+  // reading copies it out anyway.
+  let (read, rest) = read_length_prefixed::<Pair>(&out[1..]).unwrap();
+  assert_eq!(read, pairs);
+  let (read, rest) = read_length_prefixed_u64::<u8>(rest).unwrap();
+  assert_eq!(read, [7, 8, 9]);
+  let (read, rest) = read_length_prefixed::<u64>(rest).unwrap();
+  assert!(read.is_empty());
+  assert!(rest.is_empty());
+
+  // too short for the prefix, or for the elements.
+  assert_eq!(
+    read_length_prefixed::<u8>(&[1, 0, 0]),
+    Err(PodCastError::SizeMismatch)
+  );
+  assert_eq!(
+    read_length_prefixed::<Pair>(&out[1..20]),
+    Err(PodCastError::SizeMismatch)
+  );
+  assert_eq!(
+    read_length_prefixed_u64::<u32>(&[0xFF; 12]),
+    Err(PodCastError::SizeMismatch)
+  );
+
+  let mut out = Vec::new();
+  write_length_prefixed(&[(); 3], &mut out);
+  let (units, _) = read_length_prefixed::<()>(&out).unwrap();
+  assert_eq!(units.len(), 3);
+}
+
+#[test]
+#[cfg(feature = "extern_crate_std")]
+fn test_tag_registry() {
+  use bytemuck::checked::CheckedCastError;
+
+  let mut registry = TagRegistry::new();
+  assert!(!registry.contains(*b"RGB "));
+  registry.register::<u32>(*b"SIZE");
+  assert_eq!(
+    format!("{:?}", registry),
+    "TagRegistry { tags: [[83, 73, 90, 69]] }"
+  );
+  registry.register::<Rgb>(*b"RGB ");
+  registry.register::<bool>(*b"FLAG");
+  assert!(registry.contains(*b"RGB "));
+
+  assert_eq!(registry.validate(*b"RGB ", &[1, 2, 3]), Ok(()));
+  assert_eq!(
+    registry.validate(*b"RGB ", &[1, 2]),
+    Err(TagValidationError::CheckedCastError(CheckedCastError::PodCastError(
+      PodCastError::SizeMismatch
+    )))
+  );
+  assert_eq!(registry.validate(*b"FLAG", &[1]), Ok(()));
+  assert_eq!(
+    registry.validate(*b"FLAG", &[2]),
+    Err(TagValidationError::CheckedCastError(
+      CheckedCastError::InvalidBitPattern
+    ))
+  );
+  assert_eq!(
+    registry.validate(*b"NONE", &[1]),
+    Err(TagValidationError::UnknownTag(*b"NONE"))
+  );
+
+  // registering again replaces the old type.
+  registry.register::<[u8; 2]>(*b"FLAG");
+  assert_eq!(registry.validate(*b"FLAG", &[2, 2]), Ok(()));
+  assert!(registry.validate(*b"FLAG", &[1]).is_err());
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_interleave() {
+  let ids = [1_u32, 2, 3];
+  let weights = [0.5_f32, 1.5, 2.5];
+  let mut out = Vec::new();
+  interleave(&ids, &weights, &mut out);
+  assert_eq!(out.len(), 24);
+  let pairs: Vec<Pair> = pod_collect_to_vec(&out);
+  assert_eq!(
+    pairs,
+    [Pair { a: 1, b: 0.5 }, Pair { a: 2, b: 1.5 }, Pair { a: 3, b: 2.5 }]
+  );
+
+  // more output is appended after what's already there.
+  interleave(&[4_u8], &[5_u8], &mut out);
+  assert_eq!(&out[24..], &[4, 5]);
+
+  assert_eq!(
+    try_interleave(&ids, &weights[..2], &mut out),
+    Err(PodCastError::SizeMismatch)
+  );
+  assert_eq!(out.len(), 26);
+
+  let mut out = Vec::new();
+  interleave3(&[1_u8, 2], &[[3_u8; 2], [4; 2]], &[(), ()], &mut out);
+  assert_eq!(out, [1, 3, 3, 2, 4, 4]);
+  assert_eq!(
+    try_interleave3(&[1_u8], &[2_u8], &[0_u8; 0], &mut out),
+    Err(PodCastError::SizeMismatch)
+  );
+  interleave::<u16, u16>(&[], &[], &mut out);
+  assert_eq!(out.len(), 6);
+}
+
+#[test]
+#[cfg(feature = "extern_crate_std")]
+fn test_io_slices() {
+  use std::io::{Read, Write};
+
+  let words = [0x0102_0304_u32, 0x0506_0708, 9];
+  let slice = as_io_slice(&words);
+  assert_eq!(slice.len(), 12);
+  assert_eq!(&*slice, cast_slice::<u32, u8>(&words));
+  assert_eq!(as_io_slice::<u32>(&[]).len(), 0);
+  assert_eq!(as_io_slice(&[(); 5]).len(), 0);
+
+  let mut out = Vec::new();
+  let written = out
+    .write_vectored(&[as_io_slice(&words[..1]), as_io_slice(&words[1..])])
+    .unwrap();
+  assert_eq!(written, 12);
+  assert_eq!(out, cast_slice::<u32, u8>(&words));
+
+  let mut a = [0_u32; 2];
+  let mut b = [0_u32; 1];
+  let read = (&out[..])
+    .read_vectored(&mut [as_io_slice_mut(&mut a), as_io_slice_mut(&mut b)])
+    .unwrap();
+  assert_eq!(read, 12);
+  assert_eq!((a, b), ([words[0], words[1]], [words[2]]));
+}
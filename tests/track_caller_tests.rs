@@ -0,0 +1,64 @@
+#![cfg(feature = "track_caller")]
+
+use bytemuck::*;
+use std::{cell::Cell, panic};
+
+thread_local! {
+  // `const` thread local initializers need 1.59, past the feature's MSRV.
+  #[allow(clippy::missing_const_for_thread_local)]
+  static PANIC_LINE: Cell<Option<u32>> = Cell::new(None);
+}
+
+/// Runs `f`, which must panic, and gives the line the panic was reported at.
+fn panic_line<R, F: FnOnce() -> R + panic::UnwindSafe>(f: F) -> u32 {
+  PANIC_LINE.with(|line| line.set(None));
+  assert!(panic::catch_unwind(f).is_err());
+  PANIC_LINE.with(|line| line.get()).unwrap()
+}
+
+#[test]
+fn test_panics_point_at_the_caller() {
+  let old_hook = panic::take_hook();
+  panic::set_hook(Box::new(|info| {
+    if info.location().map(|l| l.file()) == Some(file!()) {
+      PANIC_LINE.with(|line| line.set(info.location().map(|l| l.line())));
+    }
+  }));
+
+  let line = line!() + 1;
+  let got = panic_line(|| cast::<u32, u16>(1));
+  assert_eq!(got, line);
+
+  let line = line!() + 1;
+  let got = panic_line(|| *cast_ref::<u32, u16>(&1));
+  assert_eq!(got, line);
+
+  let line = line!() + 1;
+  let got = panic_line(|| *cast_mut::<u32, u16>(&mut 1));
+  assert_eq!(got, line);
+
+  let line = line!() + 1;
+  let got = panic_line(|| cast_slice::<u8, [u8; 2]>(&[1, 2, 3]).len());
+  assert_eq!(got, line);
+
+  let line = line!() + 1;
+  let got = panic_line(|| cast_slice_mut::<u8, [u8; 2]>(&mut [1, 2, 3]).len());
+  assert_eq!(got, line);
+
+  let line = line!() + 1;
+  let got = panic_line(|| *from_bytes::<[u8; 2]>(&[1, 2, 3]));
+  assert_eq!(got, line);
+
+  let line = line!() + 1;
+  let got = panic_line(|| *from_bytes_mut::<[u8; 2]>(&mut [1, 2, 3]));
+  assert_eq!(got, line);
+
+  #[cfg(feature = "extern_crate_alloc")]
+  {
+    let line = line!() + 1;
+    let got = panic_line(|| cast_vec::<u8, [u8; 2]>(vec![1, 2, 3]));
+    assert_eq!(got, line);
+  }
+
+  panic::set_hook(old_hook);
+}